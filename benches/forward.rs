@@ -0,0 +1,34 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use iex::{iex, Outcome};
+
+#[iex]
+#[inline(never)]
+fn leaf(n: u32) -> Result<u32, &'static str> {
+    if n == u32::MAX {
+        Err("Overflow")
+    } else {
+        Ok(n + 1)
+    }
+}
+
+#[iex]
+fn wrapper(n: u32) -> Result<u32, &'static str> {
+    Ok(leaf(n)?)
+}
+
+#[iex]
+fn wrapper_twice(n: u32) -> Result<u32, &'static str> {
+    Ok(wrapper(n)?)
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("forward");
+    group.bench_function("direct", |b| b.iter(|| leaf(black_box(1)).into_result()));
+    group.bench_function("two wrappers", |b| {
+        b.iter(|| wrapper_twice(black_box(1)).into_result())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,34 @@
+// Functions whose optimized LLVM IR is checked by tests/codegen.rs
+
+use iex::{iex, Outcome};
+
+#[iex]
+fn never_raises(x: u32) -> Result<u32, &'static str> {
+    Ok(x / 2)
+}
+
+#[iex]
+fn may_raise(x: u32) -> Result<u32, &'static str> {
+    if x == 0 {
+        Err("zero")
+    } else {
+        Ok(x / 2)
+    }
+}
+
+#[no_mangle]
+pub fn resolve_never_raises(x: u32) -> u32 {
+    never_raises(x).into_result().unwrap_or(0)
+}
+
+#[no_mangle]
+pub fn resolve_may_raise(x: u32) -> u32 {
+    may_raise(x).into_result().unwrap_or(0)
+}
+
+#[no_mangle]
+pub fn resolve_constant() -> u32 {
+    never_raises(84).into_result().unwrap_or(0)
+}
+
+fn main() {}
@@ -0,0 +1,110 @@
+// Function shapes whose rustdoc signatures are compared with and without #[iex] by tests/rustdoc.rs.
+// The shapes are defined twice: in `iex_shapes` with #[iex] applied, and in `plain_shapes` with a
+// no-op attribute instead.
+
+#![allow(dead_code, clippy::result_unit_err)]
+
+use std::fmt::Debug;
+
+pub struct Wrapper<'a, T>(&'a T);
+
+macro_rules! shapes {
+    ($module:ident, $attr:meta, $boxed:meta) => {
+        pub mod $module {
+            use super::*;
+            #[$attr]
+            pub fn simple(x: u32) -> Result<u32, String> {
+                Ok(x)
+            }
+
+            #[$attr]
+            pub fn generic<T: Clone + Debug, E>(x: &T) -> Result<T, E>
+            where
+                E: From<String>,
+            {
+                Ok(x.clone())
+            }
+
+            #[$attr]
+            pub fn lifetimes<'a, 'b: 'a>(x: &'a str, _y: &'b str) -> Result<&'a str, &'b str> {
+                Ok(x)
+            }
+
+            #[$attr]
+            pub fn elided(x: &str, _wrapper: Wrapper<'_, u8>) -> Result<usize, ()> {
+                Ok(x.len())
+            }
+
+            #[$attr]
+            pub fn elided_output(x: &str) -> Result<&str, ()> {
+                Ok(x)
+            }
+
+            #[$attr]
+            pub fn impl_trait_arg(x: impl Into<u64>) -> Result<u64, ()> {
+                Ok(x.into())
+            }
+
+            #[$attr]
+            pub fn impl_trait_output(n: u8) -> Result<impl Iterator<Item = u8>, String> {
+                Ok(0..n)
+            }
+
+            #[$attr]
+            pub fn const_generic<const N: usize>(x: [u8; N]) -> Result<[u8; N], ()> {
+                Ok(x)
+            }
+
+            #[$attr]
+            pub fn option(x: Option<u32>) -> Option<u32> {
+                Some(x? + 1)
+            }
+
+            #[$attr]
+            pub async fn asynchronous(x: &str) -> Result<usize, String> {
+                Ok(x.len())
+            }
+
+            #[doc(hidden)]
+            #[$attr]
+            pub fn hidden() -> Result<(), ()> {
+                Ok(())
+            }
+
+            pub struct Methods;
+
+            impl Methods {
+                #[$attr]
+                pub fn by_ref<'a>(&'a self, x: &'a u8) -> Result<&'a u8, ()> {
+                    Ok(x)
+                }
+
+                #[$attr]
+                pub fn by_mut(&mut self) -> Result<&mut Self, ()> {
+                    Ok(self)
+                }
+            }
+
+            pub trait Trait {
+                #[$attr]
+                fn required(&self) -> Result<u32, String>;
+
+                #[$boxed]
+                fn boxed(&self, x: &str) -> Result<u32, String>;
+
+                #[$attr]
+                fn provided<T: Debug>(self, x: T) -> Result<String, String>
+                where
+                    Self: Sized,
+                {
+                    Ok(format!("{x:?}"))
+                }
+            }
+        }
+    };
+}
+
+shapes!(iex_shapes, iex::iex, iex::iex(boxed));
+shapes!(plain_shapes, allow(unused), allow(unused));
+
+fn main() {}
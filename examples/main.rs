@@ -0,0 +1,15 @@
+use iex::iex;
+use std::error::Error;
+
+#[iex]
+fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    s.parse()
+}
+
+#[iex(main)]
+fn main() -> Result<(), Box<dyn Error>> {
+    let arg = std::env::args().nth(1).unwrap_or_default();
+    let value = parse(&arg)?;
+    println!("{}", value * 2);
+    Ok(())
+}
@@ -0,0 +1,27 @@
+// Drops outcomes of #[must_use] functions. tests/must_use.rs builds this with the lint forced on and
+// checks the warnings.
+
+#![allow(unused_must_use)]
+
+use iex::iex;
+
+#[must_use = "the parsed value must be checked"]
+#[iex]
+fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+    s.parse()
+}
+
+struct Parser;
+
+impl Parser {
+    #[must_use = "the parsed port must be checked"]
+    #[iex]
+    fn parse_port(&self, s: &str) -> Result<u16, std::num::ParseIntError> {
+        s.parse()
+    }
+}
+
+fn main() {
+    parse("1");
+    Parser.parse_port("80");
+}
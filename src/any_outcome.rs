@@ -0,0 +1,84 @@
+use crate::Outcome;
+use alloc::boxed::Box;
+use core::any::{Any, TypeId};
+
+type ErasedResolver<'a, T> = Box<dyn FnOnce() -> Result<T, Box<dyn Any>> + 'a>;
+
+/// A type-erased outcome with a known success type.
+///
+/// Outcomes with different error types cannot be stored in the same collection, because
+/// `#[iex] Result` is an opaque type. `AnyOutcome` erases the error type and remembers its
+/// [`TypeId`] instead, so that, for instance, a registry of fallible handlers can be keyed by the
+/// error type.
+///
+/// The outcome is resolved lazily, when it is downcast.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, AnyOutcome};
+///
+/// #[iex]
+/// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// #[iex]
+/// fn check(x: i32) -> Result<i32, &'static str> {
+///     if x >= 0 { Ok(x) } else { Err("negative") }
+/// }
+///
+/// let outcomes = [AnyOutcome::new(parse("12")), AnyOutcome::new(check(-1))];
+/// for outcome in outcomes {
+///     if outcome.is::<&'static str>() {
+///         assert_eq!(outcome.downcast_into_result::<&'static str>(), Some(Err("negative")));
+///     } else {
+///         assert_eq!(outcome.downcast_into_result::<std::num::ParseIntError>(), Some(Ok(12)));
+///     }
+/// }
+/// ```
+pub struct AnyOutcome<'a, T> {
+    error_type_id: TypeId,
+    resolve: ErasedResolver<'a, T>,
+}
+
+impl<'a, T> AnyOutcome<'a, T> {
+    /// Erase the error type of an outcome.
+    pub fn new<R: Outcome<Output = T> + 'a>(outcome: R) -> Self
+    where
+        R::Error: 'static,
+    {
+        Self {
+            error_type_id: TypeId::of::<R::Error>(),
+            resolve: Box::new(move || {
+                outcome
+                    .into_result()
+                    .map_err(|err| Box::new(err) as Box<dyn Any>)
+            }),
+        }
+    }
+
+    /// The [`TypeId`] of the erased error type.
+    pub fn error_type_id(&self) -> TypeId {
+        self.error_type_id
+    }
+
+    /// Returns `true` if the erased error type is `E`.
+    pub fn is<E: 'static>(&self) -> bool {
+        self.error_type_id == TypeId::of::<E>()
+    }
+
+    /// Resolve the outcome if the erased error type is `E`.
+    ///
+    /// Returns [`None`] without resolving the outcome if the error type is not `E`.
+    pub fn downcast_into_result<E: 'static>(self) -> Option<Result<T, E>> {
+        if !self.is::<E>() {
+            crate::iex_result::discard(self);
+            return None;
+        }
+        Some((self.resolve)().map_err(|err| {
+            *err.downcast::<E>()
+                .unwrap_or_else(|_| unreachable!("error type ID mismatch"))
+        }))
+    }
+}
@@ -0,0 +1,27 @@
+use core::convert::Infallible;
+use core::ops::ControlFlow;
+
+/// A [`Try`](core::ops::Try) residual that can be built from an error.
+///
+/// [`Outcome::catch_into`](crate::Outcome::catch_into) resolves into any `Try` type whose residual
+/// implements this trait. It's implemented for the residuals of [`Result`], converting the error
+/// with [`From`], and of [`ControlFlow`], which breaks with the converted error. Implement it for
+/// the residual of a custom `Try` type to make the type a valid target.
+///
+/// Requires the `nightly` feature.
+pub trait ErrorResidual<E> {
+    /// Build the residual from an error.
+    fn from_error(error: E) -> Self;
+}
+
+impl<E, F: From<E>> ErrorResidual<E> for Result<Infallible, F> {
+    fn from_error(error: E) -> Self {
+        Err(error.into())
+    }
+}
+
+impl<E, B: From<E>> ErrorResidual<E> for ControlFlow<B, Infallible> {
+    fn from_error(error: E) -> Self {
+        ControlFlow::Break(error.into())
+    }
+}
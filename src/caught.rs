@@ -0,0 +1,41 @@
+use std::backtrace::Backtrace;
+
+/// An error caught together with the backtrace of the point where it was raised.
+///
+/// Returned by [`into_result_with_backtrace`](crate::Outcome::into_result_with_backtrace).
+#[derive(Debug)]
+pub struct Caught<E> {
+    error: E,
+    backtrace: Option<Backtrace>,
+}
+
+impl<E> Caught<E> {
+    pub(crate) fn new(error: E, backtrace: Option<Backtrace>) -> Self {
+        Self { error, backtrace }
+    }
+
+    /// The caught error.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Extract the caught error, discarding the backtrace.
+    pub fn into_error(self) -> E {
+        self.error
+    }
+
+    /// The backtrace of the point where the error was raised.
+    ///
+    /// This is `None` if the error never propagated by unwinding, e.g. if it was returned by
+    /// [`Result::into_result_with_backtrace`](crate::Outcome::into_result_with_backtrace), or if the
+    /// backtrace was consumed by [`map_err_backtrace`](crate::Outcome::map_err_backtrace) during
+    /// propagation. The backtrace is captured with [`Backtrace::capture`], so it may be disabled.
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        self.backtrace.as_ref()
+    }
+
+    /// Split into the error and the backtrace.
+    pub fn into_parts(self) -> (E, Option<Backtrace>) {
+        (self.error, self.backtrace)
+    }
+}
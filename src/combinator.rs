@@ -0,0 +1,167 @@
+use crate::{
+    iex_result::CallWithMarker,
+    imp::{ExceptionMapper, IexResult, Marker},
+    Outcome,
+};
+use core::marker::PhantomData;
+
+// A transformation applied to an outcome while it's being resolved.
+//
+// Steps don't resolve the inner outcome themselves: they receive a resolver that turns a marker
+// into the value, raising the error through the marker. This keeps the driving logic in
+// Combinator, so that the same steps can back resolution strategies other than
+// get_value_or_panic.
+pub(crate) trait Step<T, E> {
+    type Output;
+    type Error;
+
+    fn apply(
+        self,
+        resolve: impl FnOnce(Marker<E>) -> T,
+        marker: Marker<Self::Error>,
+    ) -> Self::Output;
+
+    // Map and MapErr override these to compose the closures instead of adding a step
+    #[inline(always)]
+    fn then_map<U, O: FnOnce(Self::Output) -> U>(
+        self,
+        op: O,
+    ) -> impl Step<T, E, Output = U, Error = Self::Error>
+    where
+        Self: Sized,
+    {
+        Then(self, Map(op))
+    }
+
+    #[inline(always)]
+    fn then_map_err<F, O: FnOnce(Self::Error) -> F>(
+        self,
+        op: O,
+    ) -> impl Step<T, E, Output = Self::Output, Error = F>
+    where
+        Self: Sized,
+    {
+        Then(self, MapErr(op))
+    }
+}
+
+// An outcome with a step applied to it, resolved synchronously
+pub struct Combinator<R, S>(pub(crate) R, pub(crate) S);
+
+impl<R: Outcome, S: Step<R::Output, R::Error>> CallWithMarker<S::Output, S::Error>
+    for Combinator<R, S>
+{
+    #[inline(always)]
+    fn call_with_marker(self, marker: Marker<S::Error>) -> S::Output {
+        let Combinator(outcome, step) = self;
+        step.apply(|marker| outcome.get_value_or_panic(marker), marker)
+    }
+
+    #[inline(always)]
+    fn then_map<U, O: FnOnce(S::Output) -> U>(self, op: O) -> impl CallWithMarker<U, S::Error> {
+        Combinator(self.0, self.1.then_map(op))
+    }
+
+    #[inline(always)]
+    fn then_map_err<F, O: FnOnce(S::Error) -> F>(self, op: O) -> impl CallWithMarker<S::Output, F> {
+        Combinator(self.0, self.1.then_map_err(op))
+    }
+}
+
+// Two steps applied one after another
+pub struct Then<S1, S2>(S1, S2);
+
+impl<T, E, S1: Step<T, E>, S2: Step<S1::Output, S1::Error>> Step<T, E> for Then<S1, S2> {
+    type Output = S2::Output;
+    type Error = S2::Error;
+
+    #[inline(always)]
+    fn apply(self, resolve: impl FnOnce(Marker<E>) -> T, marker: Marker<S2::Error>) -> S2::Output {
+        let Then(first, second) = self;
+        second.apply(|marker| first.apply(resolve, marker), marker)
+    }
+
+    #[inline(always)]
+    fn then_map<U, O: FnOnce(S2::Output) -> U>(
+        self,
+        op: O,
+    ) -> impl Step<T, E, Output = U, Error = S2::Error> {
+        Then(self.0, self.1.then_map(op))
+    }
+
+    #[inline(always)]
+    fn then_map_err<F, O: FnOnce(S2::Error) -> F>(
+        self,
+        op: O,
+    ) -> impl Step<T, E, Output = S2::Output, Error = F> {
+        Then(self.0, self.1.then_map_err(op))
+    }
+}
+
+pub struct Map<O>(pub(crate) O);
+
+impl<T, E, U, O: FnOnce(T) -> U> Step<T, E> for Map<O> {
+    type Output = U;
+    type Error = E;
+
+    #[inline(always)]
+    fn apply(self, resolve: impl FnOnce(Marker<E>) -> T, marker: Marker<E>) -> U {
+        (self.0)(resolve(marker))
+    }
+
+    #[inline(always)]
+    fn then_map<V, P: FnOnce(U) -> V>(self, op: P) -> impl Step<T, E, Output = V, Error = E> {
+        let first = self.0;
+        Map(move |value| op(first(value)))
+    }
+}
+
+pub struct MapErr<O>(pub(crate) O);
+
+impl<T, E, F, O: FnOnce(E) -> F> Step<T, E> for MapErr<O> {
+    type Output = T;
+    type Error = F;
+
+    #[inline(always)]
+    fn apply(self, resolve: impl FnOnce(Marker<E>) -> T, marker: Marker<F>) -> T {
+        let op = self.0;
+        let exception_mapper = ExceptionMapper::new(marker, (), |(), err| op(err));
+        let value = resolve(exception_mapper.get_in_marker());
+        exception_mapper.swallow();
+        value
+    }
+
+    #[inline(always)]
+    fn then_map_err<G, P: FnOnce(F) -> G>(self, op: P) -> impl Step<T, E, Output = T, Error = G> {
+        let first = self.0;
+        MapErr(move |err| op(first(err)))
+    }
+}
+
+pub struct AndThen<O>(pub(crate) O);
+
+impl<T, E, U: Outcome<Error = E>, O: FnOnce(T) -> U> Step<T, E> for AndThen<O> {
+    type Output = U::Output;
+    type Error = E;
+
+    #[inline(always)]
+    fn apply(self, resolve: impl FnOnce(Marker<E>) -> T, marker: Marker<E>) -> U::Output {
+        (self.0)(resolve(marker)).get_value_or_panic(marker)
+    }
+}
+
+pub struct OrElse<O>(pub(crate) O);
+
+impl<T, E, U: Outcome<Output = T>, O: FnOnce(E) -> U> Step<T, E> for OrElse<O> {
+    type Output = T;
+    type Error = U::Error;
+
+    #[inline(always)]
+    fn apply(self, resolve: impl FnOnce(Marker<E>) -> T, marker: Marker<U::Error>) -> T {
+        // Recovering requires stopping the unwinding, so the inner error has to be caught
+        match IexResult(resolve, PhantomData).into_result() {
+            Ok(value) => value,
+            Err(err) => (self.0)(err).get_value_or_panic(marker),
+        }
+    }
+}
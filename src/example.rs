@@ -1,6 +1,7 @@
 //! Examples of rendered documentation for [`#[iex]`](macro@iex) functions.
 
 use crate::iex;
+use alloc::string::{String, ToString};
 
 /// A simple struct containing an [`#[iex]`](macro@iex) method.
 pub struct HasIexMethod;
@@ -1,7 +1,17 @@
-use std::mem::{align_of, size_of, MaybeUninit};
+use crate::Payload;
+use alloc::boxed::Box;
+use core::mem::{align_of, size_of, MaybeUninit};
 
 pub(crate) struct Exception {
     data: MaybeUninit<[usize; 8]>,
+    // A panic in an error handler, e.g. a map_err closure, that occurred while the error was being
+    // propagated. It belongs to the same propagation as the stored error, so it's saved, restored
+    // and cleared together with it.
+    secondary_panic: Option<Payload>,
+    // The backtrace of the point where the stored error was raised. It stays with the error when
+    // the error is mapped.
+    #[cfg(feature = "backtrace")]
+    backtrace: Option<std::backtrace::Backtrace>,
 }
 
 #[repr(C)]
@@ -14,11 +24,14 @@ impl Exception {
     pub(crate) const fn new() -> Self {
         Self {
             data: MaybeUninit::zeroed(),
+            secondary_panic: None,
+            #[cfg(feature = "backtrace")]
+            backtrace: None,
         }
     }
 
     const fn is_small<T>() -> bool {
-        size_of::<Just<T>>() <= size_of::<Exception>()
+        size_of::<Just<T>>() <= size_of::<[usize; 8]>()
     }
 
     unsafe fn write_raw<T>(&mut self, value: T) {
@@ -45,6 +58,49 @@ impl Exception {
 
     pub(crate) fn clear(&mut self) {
         unsafe { self.write_raw(0usize) }
+        self.secondary_panic = None;
+        #[cfg(feature = "backtrace")]
+        {
+            self.backtrace = None;
+        }
+    }
+
+    pub(crate) fn stash_secondary_panic(&mut self, payload: Payload) {
+        // Keep the first panic, it's likely the root cause
+        self.secondary_panic.get_or_insert(payload);
+    }
+
+    pub(crate) fn take_secondary_panic(&mut self) -> Option<Payload> {
+        self.secondary_panic.take()
+    }
+
+    #[cfg(feature = "backtrace")]
+    pub(crate) fn set_backtrace(&mut self, backtrace: std::backtrace::Backtrace) {
+        self.backtrace = Some(backtrace);
+    }
+
+    #[cfg(feature = "backtrace")]
+    pub(crate) fn take_backtrace(&mut self) -> Option<std::backtrace::Backtrace> {
+        self.backtrace.take()
+    }
+
+    // Both the inline and the boxed representations start with a non-zero word when a value is
+    // stored, and clear() zeroes it. A secondary panic may be pending even if the error was
+    // consumed by the panicking handler.
+    pub(crate) fn is_occupied(&self) -> bool {
+        self.secondary_panic.is_some() || unsafe { self.read_raw::<usize>() != 0 }
+    }
+
+    // Move the contents out, leaving the exception cleared
+    pub(crate) fn take(&mut self) -> Exception {
+        let taken = Exception {
+            data: self.data,
+            secondary_panic: self.secondary_panic.take(),
+            #[cfg(feature = "backtrace")]
+            backtrace: self.backtrace.take(),
+        };
+        self.clear();
+        taken
     }
 
     unsafe fn read_raw<T>(&self) -> T {
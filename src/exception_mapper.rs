@@ -1,6 +1,6 @@
-use crate::{imp::Marker, EXCEPTION};
-use std::marker::PhantomData;
-use std::mem::ManuallyDrop;
+use crate::{imp::Marker, Host, Platform};
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
 
 pub struct ExceptionMapper<S, T, U, F: FnOnce(S, T) -> U> {
     state: ManuallyDrop<S>,
@@ -36,14 +36,24 @@ impl<S, T, U, F: FnOnce(S, T) -> U> ExceptionMapper<S, T, U, F> {
 impl<S, T, U, F: FnOnce(S, T) -> U> Drop for ExceptionMapper<S, T, U, F> {
     fn drop(&mut self) {
         // Resolve TLS just once
-        EXCEPTION.with(|exception| unsafe {
+        Host::with_exception(|exception| unsafe {
             let exception = exception.get();
             // Dereference twice instead of keeping a &mut around, because self.0() may call a
             // function that uses 'exception'.
             if let Some(error) = (*exception).read::<T>() {
+                #[cfg(all(feature = "realtime", debug_assertions))]
+                let _guard = crate::realtime::ErrorPathGuard::enter();
                 let state = ManuallyDrop::take(&mut self.state);
                 let f = ManuallyDrop::take(&mut self.f);
-                (*exception).write::<U>(f(state, error));
+                // We're unwinding, so a panic in 'f' would abort the process. Stash it for
+                // into_result() to handle instead.
+                match Host::catch_unwind(|| f(state, error)) {
+                    Ok(error) => (*exception).write::<U>(error),
+                    Err(payload) => {
+                        (*exception).clear();
+                        (*exception).stash_secondary_panic(payload);
+                    }
+                }
             }
         })
     }
@@ -0,0 +1,154 @@
+use crate::{
+    iex_result::CallWithMarker,
+    imp::{IexResult, Marker},
+    Outcome,
+};
+use eyre::Report;
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+/// [`eyre`](https://docs.rs/eyre/latest/eyre/) compatibility layer.
+///
+/// [`eyre::WrapErr`] does not work with `#[iex] Result`, but this does.
+///
+/// # Example
+///
+/// ```rust
+/// use eyre::{bail, Result};
+/// use iex::{iex, WrapErr};
+///
+/// #[iex]
+/// fn returns_eyre_report() -> Result<()> {
+///     bail!(r"¯\_(ツ)_/¯");
+/// }
+///
+/// #[iex]
+/// fn wraps_eyre_report() -> Result<()> {
+///     returns_eyre_report().wrap_err("In wraps_eyre_report()")
+/// }
+/// ```
+pub trait WrapErr<T, E> {
+    type WrapErrOutcome<D>: Outcome<Output = T, Error = Report>
+    where
+        Result<(), E>: eyre::WrapErr<(), E>,
+        D: Display + Send + Sync + 'static;
+
+    type WrapErrWithOutcome<D, F>: Outcome<Output = T, Error = Report>
+    where
+        Result<(), E>: eyre::WrapErr<(), E>,
+        D: Display + Send + Sync + 'static,
+        F: FnOnce() -> D;
+
+    /// Wrap the error value with a new adhoc error.
+    fn wrap_err<D>(self, msg: D) -> Self::WrapErrOutcome<D>
+    where
+        Result<(), E>: eyre::WrapErr<(), E>,
+        D: Display + Send + Sync + 'static;
+
+    /// Wrap the error value with a new adhoc error that is evaluated lazily only once an error
+    /// does occur.
+    fn wrap_err_with<D, F>(self, f: F) -> Self::WrapErrWithOutcome<D, F>
+    where
+        Result<(), E>: eyre::WrapErr<(), E>,
+        D: Display + Send + Sync + 'static,
+        F: FnOnce() -> D;
+}
+
+impl<T, E> WrapErr<T, E> for Result<T, E> {
+    type WrapErrOutcome<D>
+        = Result<T, Report>
+    where
+        Result<(), E>: eyre::WrapErr<(), E>,
+        D: Display + Send + Sync + 'static;
+
+    type WrapErrWithOutcome<D, F>
+        = Result<T, Report>
+    where
+        Result<(), E>: eyre::WrapErr<(), E>,
+        D: Display + Send + Sync + 'static,
+        F: FnOnce() -> D;
+
+    fn wrap_err<D>(self, msg: D) -> Result<T, Report>
+    where
+        Result<(), E>: eyre::WrapErr<(), E>,
+        D: Display + Send + Sync + 'static,
+    {
+        self.map_err(|e| eyre::WrapErr::wrap_err(Err(e), msg).unwrap_err())
+    }
+
+    fn wrap_err_with<D, F>(self, f: F) -> Result<T, Report>
+    where
+        Result<(), E>: eyre::WrapErr<(), E>,
+        D: Display + Send + Sync + 'static,
+        F: FnOnce() -> D,
+    {
+        self.map_err(|e| eyre::WrapErr::wrap_err_with(Err(e), f).unwrap_err())
+    }
+}
+
+impl<T, E, Func: CallWithMarker<T, E>> WrapErr<T, E> for IexResult<T, E, Func> {
+    type WrapErrOutcome<D>
+        = IexResult<T, Report, GenericWrapErr<Self, D>>
+    where
+        Result<(), E>: eyre::WrapErr<(), E>,
+        D: Display + Send + Sync + 'static;
+
+    type WrapErrWithOutcome<D, F>
+        = IexResult<T, Report, GenericWrapErrWith<Self, D, F>>
+    where
+        Result<(), E>: eyre::WrapErr<(), E>,
+        D: Display + Send + Sync + 'static,
+        F: FnOnce() -> D;
+
+    fn wrap_err<D>(self, msg: D) -> Self::WrapErrOutcome<D>
+    where
+        Result<(), E>: eyre::WrapErr<(), E>,
+        D: Display + Send + Sync + 'static,
+    {
+        IexResult(GenericWrapErr { outcome: self, msg }, PhantomData)
+    }
+
+    fn wrap_err_with<D, F>(self, f: F) -> Self::WrapErrWithOutcome<D, F>
+    where
+        Result<(), E>: eyre::WrapErr<(), E>,
+        D: Display + Send + Sync + 'static,
+        F: FnOnce() -> D,
+    {
+        IexResult(GenericWrapErrWith { outcome: self, f }, PhantomData)
+    }
+}
+
+pub struct GenericWrapErr<R, D> {
+    outcome: R,
+    msg: D,
+}
+
+impl<R: Outcome, D> CallWithMarker<R::Output, Report> for GenericWrapErr<R, D>
+where
+    Result<(), R::Error>: eyre::WrapErr<(), R::Error>,
+    D: Display + Send + Sync + 'static,
+{
+    fn call_with_marker(self, marker: Marker<Report>) -> R::Output {
+        self.outcome
+            .map_err(|e| eyre::WrapErr::wrap_err(Err(e), self.msg).unwrap_err())
+            .get_value_or_panic(marker)
+    }
+}
+
+pub struct GenericWrapErrWith<R, D, F: FnOnce() -> D> {
+    outcome: R,
+    f: F,
+}
+
+impl<R: Outcome, D, F: FnOnce() -> D> CallWithMarker<R::Output, Report>
+    for GenericWrapErrWith<R, D, F>
+where
+    Result<(), R::Error>: eyre::WrapErr<(), R::Error>,
+    D: Display + Send + Sync + 'static,
+{
+    fn call_with_marker(self, marker: Marker<Report>) -> R::Output {
+        self.outcome
+            .map_err(|e| eyre::WrapErr::wrap_err(Err(e), (self.f)()).unwrap_err())
+            .get_value_or_panic(marker)
+    }
+}
@@ -2,7 +2,8 @@ use crate::{
     imp::{ExceptionMapper, Marker},
     Outcome,
 };
-use std::mem::ManuallyDrop;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
 
 pub trait _IexForward {
     type Output;
@@ -40,3 +41,62 @@ impl<R: Outcome> _IexForward for (Marker<R::Error>, ManuallyDrop<R>) {
         ManuallyDrop::into_inner(self.1).get_value_or_panic(self.0)
     }
 }
+
+// `x.into_result()?` in an #[iex] function is rewritten to use this. If `x` is an outcome and
+// `into_result` resolves to `Outcome::into_result`, `x` is forwarded directly instead of being
+// resolved and raised again, which would install a redundant catch frame. Otherwise, `into_result`
+// is something else entirely, e.g. a method of an extension trait, so it's called as written.
+//
+// Whether `Outcome` is in scope is checked by resolving `x._iex_outcome_in_scope()` next to the
+// call: the method of `Outcome` is preferred if the trait is in scope, and `_IexOutcomeProbe`, which
+// requires autoref, is used otherwise. The type of the result selects the implementation.
+pub struct _IexResolved<R, F, U, P>(R, F, PhantomData<fn() -> (U, P)>);
+
+impl<R, F: FnOnce(R) -> U, U, P> _IexResolved<R, F, U, P> {
+    pub fn new(value: R, resolve: F, _probe: impl FnOnce(&R) -> P) -> ManuallyDrop<Self> {
+        ManuallyDrop::new(Self(value, resolve, PhantomData))
+    }
+}
+
+pub struct _IexOutcomeInScope;
+
+pub trait _IexOutcomeProbe {
+    fn _iex_outcome_in_scope(&self) {}
+}
+
+impl<T: ?Sized> _IexOutcomeProbe for &T {}
+
+pub trait _IexForwardResolved {
+    type Output;
+    fn _iex_forward_resolved(self) -> Self::Output;
+}
+
+impl<E, R: Outcome, F, U> _IexForwardResolved
+    for (
+        Marker<E>,
+        ManuallyDrop<_IexResolved<R, F, U, _IexOutcomeInScope>>,
+    )
+where
+    R::Error: Into<E>,
+{
+    type Output = R::Output;
+    fn _iex_forward_resolved(self) -> R::Output {
+        let outcome = ManuallyDrop::into_inner(self.1).0;
+        <&mut (Marker<E>, ManuallyDrop<R>)>::_iex_forward(&mut (self.0, ManuallyDrop::new(outcome)))
+    }
+}
+
+impl<E, R, F: FnOnce(R) -> U, U: Outcome, P> _IexForwardResolved
+    for &mut (Marker<E>, ManuallyDrop<_IexResolved<R, F, U, P>>)
+where
+    U::Error: Into<E>,
+{
+    type Output = U::Output;
+    fn _iex_forward_resolved(self) -> U::Output {
+        let _IexResolved(value, resolve, _) = unsafe { ManuallyDrop::take(&mut self.1) };
+        <&mut (Marker<E>, ManuallyDrop<U>)>::_iex_forward(&mut (
+            self.0,
+            ManuallyDrop::new(resolve(value)),
+        ))
+    }
+}
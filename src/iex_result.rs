@@ -1,13 +1,49 @@
+#[cfg(feature = "backtrace")]
+use crate::Caught;
 use crate::{
-    imp::{ExceptionMapper, Marker},
+    combinator::{Combinator, Map, MapErr},
+    exception::Exception,
+    imp::Marker,
     outcome::Sealed,
-    IexPanic, Outcome, EXCEPTION,
+    panic_hook::resume_foreign_panic,
+    stash_secondary_panic, Host, IexPanic, Outcome, Payload, Platform, ResolveError,
 };
-use std::marker::PhantomData;
-use std::panic::AssertUnwindSafe;
+use alloc::boxed::Box;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
 
 pub(crate) trait CallWithMarker<T, E> {
+    // If false, call_with_marker never raises an error, so into_result can avoid catch_unwind
+    const MAY_RAISE: bool = true;
+
     fn call_with_marker(self, marker: Marker<E>) -> T;
+
+    // Resolves without catch_unwind. Only called if MAY_RAISE is false.
+    #[inline(always)]
+    fn call_without_catch(self) -> Result<T, E>
+    where
+        Self: Sized,
+    {
+        Ok(self.call_with_marker(unsafe { Marker::new() }))
+    }
+
+    // Combinators override these to fuse adjacent steps, so that e.g. `map(f).map(g)` produces a
+    // single layer instead of two
+    #[inline(always)]
+    fn then_map<U, O: FnOnce(T) -> U>(self, op: O) -> impl CallWithMarker<U, E>
+    where
+        Self: Sized,
+    {
+        Combinator(IexResult(self, PhantomData), Map(op))
+    }
+
+    #[inline(always)]
+    fn then_map_err<F, O: FnOnce(E) -> F>(self, op: O) -> impl CallWithMarker<T, F>
+    where
+        Self: Sized,
+    {
+        Combinator(IexResult(self, PhantomData), MapErr(op))
+    }
 }
 
 impl<T, E, Func: FnOnce(Marker<E>) -> T> CallWithMarker<T, E> for Func {
@@ -17,8 +53,110 @@ impl<T, E, Func: FnOnce(Marker<E>) -> T> CallWithMarker<T, E> for Func {
     }
 }
 
+// Wraps the body of an #[iex] function that provably never raises an error. The body only ever
+// evaluates to `Ok(..)`, but `Ok` might resolve to a user item that returns an error, so its result
+// is resolved rather than assumed to be a success.
+pub struct NoRaise<Func>(pub Func);
+
+impl<T, E, R, Func> CallWithMarker<T, E> for NoRaise<Func>
+where
+    R: Outcome<Output = T, Error = E>,
+    Func: FnOnce(Marker<E>) -> R,
+{
+    const MAY_RAISE: bool = false;
+
+    #[inline(always)]
+    fn call_with_marker(self, marker: Marker<E>) -> T {
+        (self.0)(marker).get_value_or_panic(marker)
+    }
+
+    #[inline(always)]
+    fn call_without_catch(self) -> Result<T, E> {
+        (self.0)(unsafe { Marker::new() }).into_result()
+    }
+}
+
 pub struct IexResult<T, E, Func>(pub Func, pub PhantomData<fn() -> (T, E)>);
 
+impl<T, E, Func> IexResult<T, E, Func> {
+    // Consumes the outcome without triggering the misuse check in debug builds
+    #[inline(always)]
+    fn into_func(self) -> Func {
+        let this = ManuallyDrop::new(self);
+        unsafe { core::ptr::read(&this.0) }
+    }
+}
+
+// Outcomes are lazy, so dropping one without resolving it means the function is never called. This
+// is usually a bug, e.g. a forgotten `?`, so debug builds with the `check-unresolved` feature
+// report it. Drops during unwinding,
+// e.g. of outcomes created before an error was raised, are expected and not reported.
+#[cfg(all(debug_assertions, feature = "check-unresolved"))]
+impl<T, E, Func> Drop for IexResult<T, E, Func> {
+    fn drop(&mut self) {
+        if !std::thread::panicking() && !DISCARDING.get() {
+            panic!(
+                "iex: an `#[iex] Result` was dropped without being resolved, so the function was \
+                 never called. Propagate it with `?` or call `.into_result()` on it"
+            );
+        }
+    }
+}
+
+#[cfg(all(debug_assertions, feature = "check-unresolved"))]
+std::thread_local! {
+    static DISCARDING: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+}
+
+// Drops outcomes that are deliberately left unresolved, e.g. the rest of the outcomes after
+// first_ok() finds a success
+pub(crate) fn discard<T>(value: T) {
+    #[cfg(all(debug_assertions, feature = "check-unresolved"))]
+    {
+        struct Reset(bool);
+
+        impl Drop for Reset {
+            fn drop(&mut self) {
+                DISCARDING.set(self.0);
+            }
+        }
+
+        let _reset = Reset(DISCARDING.replace(true));
+        drop(value);
+    }
+    #[cfg(not(all(debug_assertions, feature = "check-unresolved")))]
+    drop(value);
+}
+
+/// A boxed outcome.
+///
+/// `#[iex] Result` is an opaque type, which makes it impossible to store in struct fields or
+/// return from trait objects. `DynOutcome` erases the concrete type, at the cost of an allocation.
+/// It implements [`Outcome`], so `?` and [`into_result`](Outcome::into_result) work as usual.
+///
+/// Use [`Outcome::boxed`] to create a `DynOutcome`.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, DynOutcome, Outcome};
+///
+/// #[iex]
+/// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// struct Parser {
+///     callback: Box<dyn Fn(&str) -> DynOutcome<'_, i32, std::num::ParseIntError>>,
+/// }
+///
+/// let parser = Parser {
+///     callback: Box::new(|s| parse(s).boxed()),
+/// };
+/// assert_eq!((parser.callback)("12").into_result(), Ok(12));
+/// ```
+pub type DynOutcome<'a, T, E> = IexResult<T, E, Box<dyn FnOnce(Marker<E>) -> T + 'a>>;
+
 impl<T, E, Func> Sealed for IexResult<T, E, Func> {}
 
 impl<T, E, Func: CallWithMarker<T, E>> Outcome for IexResult<T, E, Func> {
@@ -26,7 +164,7 @@ impl<T, E, Func: CallWithMarker<T, E>> Outcome for IexResult<T, E, Func> {
     type Error = E;
 
     fn get_value_or_panic(self, marker: Marker<E>) -> T {
-        self.0.call_with_marker(marker)
+        self.into_func().call_with_marker(marker)
     }
 
     #[cfg(doc)]
@@ -37,17 +175,21 @@ impl<T, E, Func: CallWithMarker<T, E>> Outcome for IexResult<T, E, Func> {
     {
     }
 
-    #[cfg(not(doc))]
+    #[cfg(all(not(doc), not(feature = "plain")))]
     fn inspect_err<F>(self, f: F) -> impl Outcome<Output = T, Error = E>
     where
         F: FnOnce(&Self::Error),
     {
         // NB: It is impossible to implement inspect_err without writeback that map_err
         // performs. Indeed, if `f` calls an #[iex] function that returns an error, that error
-        // is saved to EXCEPTION. It is necessary to override it back with err before returning
-        // from `inspect_err(..).get_value_or_panic()`.
+        // is saved to the exception slot. It is necessary to override it back with err before
+        // returning from `inspect_err(..).get_value_or_panic()`.
         self.map_err(|err| {
-            f(&err);
+            // If `f` panics, keep propagating `err` so that into_result_preserving_panic() can
+            // return it
+            if let Err(payload) = Host::catch_unwind(|| f(&err)) {
+                stash_secondary_panic(payload);
+            }
             err
         })
     }
@@ -60,39 +202,226 @@ impl<T, E, Func: CallWithMarker<T, E>> Outcome for IexResult<T, E, Func> {
     {
     }
 
-    #[cfg(not(doc))]
+    #[cfg(all(not(doc), not(feature = "plain")))]
     fn map_err<F, O>(self, op: O) -> impl Outcome<Output = Self::Output, Error = F>
     where
         O: FnOnce(E) -> F,
     {
-        IexResult(
-            |marker| {
-                let exception_mapper = ExceptionMapper::new(marker, (), |(), err| op(err));
-                let value = self.get_value_or_panic(exception_mapper.get_in_marker());
-                exception_mapper.swallow();
-                value
-            },
-            PhantomData,
-        )
+        IexResult(self.into_func().then_map_err(op), PhantomData)
+    }
+
+    // With the `plain` feature, these methods are declared to return plain Results
+    #[cfg(all(not(doc), feature = "plain"))]
+    fn inspect_err<F>(self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&Self::Error),
+    {
+        self.into_result().inspect_err(f)
+    }
+
+    #[cfg(all(not(doc), feature = "plain"))]
+    fn map_err<F, O>(self, op: O) -> Result<T, F>
+    where
+        O: FnOnce(E) -> F,
+    {
+        self.into_result().map_err(op)
+    }
+
+    #[cfg(doc)]
+    #[crate::iex]
+    fn map<U, O>(self, op: O) -> Result<U, E>
+    where
+        O: FnOnce(T) -> U,
+    {
     }
 
+    #[cfg(not(doc))]
+    fn map<U, O>(self, op: O) -> impl Outcome<Output = U, Error = Self::Error>
+    where
+        O: FnOnce(T) -> U,
+    {
+        IexResult(self.into_func().then_map(op), PhantomData)
+    }
+
+    // The fast path for outcomes that never raise is kept separate from the catch_unwind path, so
+    // that it's always inlined and resolving e.g. a constant always-Ok function folds to a constant
+    #[inline(always)]
     fn into_result(self) -> Result<T, E> {
-        std::panic::catch_unwind(AssertUnwindSafe(|| {
-            self.0.call_with_marker(unsafe { Marker::new() })
-        }))
-        .map_err(
-            #[cold]
-            |payload| {
-                if !payload.is::<IexPanic>() {
-                    std::panic::resume_unwind(payload);
-                }
-                EXCEPTION.with(|exception| unsafe {
-                    let exception = &mut *exception.get();
-                    let error = exception.read_unchecked();
-                    exception.clear();
-                    error
-                })
-            },
-        )
+        let func = self.into_func();
+        if !Func::MAY_RAISE {
+            return func.call_without_catch();
+        }
+        resolve(func)
+    }
+
+    #[inline(always)]
+    fn into_result_preserving_panic(self) -> Result<Result<T, E>, ResolveError<E>> {
+        let func = self.into_func();
+        if !Func::MAY_RAISE {
+            return Ok(func.call_without_catch());
+        }
+        resolve_preserving_panic(func)
+    }
+
+    #[cfg(feature = "backtrace")]
+    #[inline(always)]
+    fn into_result_with_backtrace(self) -> Result<T, Caught<E>> {
+        let func = self.into_func();
+        if !Func::MAY_RAISE {
+            return func
+                .call_without_catch()
+                .map_err(|error| Caught::new(error, None));
+        }
+        resolve_with_backtrace(func)
     }
 }
+
+/// Run a closure producing an outcome and resolve the outcome to a [`Result`].
+///
+/// This is equivalent to `f().into_result()`, but reads better in test harnesses and at other
+/// boundaries where a block of `#[iex]` code is run for its result. `f` is run inside the same
+/// catch as the outcome, so an error raised while building the outcome is caught too. Panics
+/// unrelated to error propagation, including panics in `f`, are resumed unchanged.
+///
+/// Like [`into_result`](Outcome::into_result), `catch` can be nested: resolving an outcome while
+/// another error is being propagated, e.g. in a [`map_err`](Outcome::map_err) handler, doesn't
+/// affect the outer error.
+///
+/// # Example
+///
+/// ```
+/// use iex::iex;
+///
+/// #[iex]
+/// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// assert_eq!(iex::catch(|| parse("12")), Ok(12));
+/// assert!(iex::catch(|| parse("x")).is_err());
+/// ```
+pub fn catch<O: Outcome, F: FnOnce() -> O>(f: F) -> Result<O::Output, O::Error> {
+    IexResult(
+        move |marker: Marker<O::Error>| f().get_value_or_panic(marker),
+        PhantomData,
+    )
+    .into_result()
+}
+
+fn resolve<T, E, Func: CallWithMarker<T, E>>(func: Func) -> Result<T, E> {
+    #[cfg(feature = "backtrace")]
+    let scope = crate::ResolveScope::enter();
+    let _saved = SavedState::save();
+    Host::catch_unwind(|| func.call_with_marker(unsafe { Marker::new() })).map_err(
+        #[cold]
+        |payload| {
+            if !payload.is::<IexPanic>() {
+                resume_foreign(payload);
+            }
+            #[cfg(feature = "backtrace")]
+            scope.caught(crate::take_backtrace());
+            take_exception().unwrap_or_else(|err| Host::resume_unwind(err.panic))
+        },
+    )
+}
+
+fn resolve_preserving_panic<T, E, Func: CallWithMarker<T, E>>(
+    func: Func,
+) -> Result<Result<T, E>, ResolveError<E>> {
+    #[cfg(feature = "backtrace")]
+    let scope = crate::ResolveScope::enter();
+    let _saved = SavedState::save();
+    match Host::catch_unwind(|| func.call_with_marker(unsafe { Marker::new() })) {
+        Ok(value) => Ok(Ok(value)),
+        Err(payload) => {
+            if !payload.is::<IexPanic>() {
+                resume_foreign(payload);
+            }
+            #[cfg(feature = "backtrace")]
+            scope.caught(crate::take_backtrace());
+            take_exception().map(Err)
+        }
+    }
+}
+
+#[cfg(feature = "backtrace")]
+fn resolve_with_backtrace<T, E, Func: CallWithMarker<T, E>>(func: Func) -> Result<T, Caught<E>> {
+    // The backtrace is returned to the caller, so it doesn't replace last_backtrace()
+    let _scope = crate::ResolveScope::enter();
+    let _saved = SavedState::save();
+    Host::catch_unwind(|| func.call_with_marker(unsafe { Marker::new() })).map_err(
+        #[cold]
+        |payload| {
+            if !payload.is::<IexPanic>() {
+                resume_foreign(payload);
+            }
+            let backtrace = crate::take_backtrace();
+            let error = take_exception().unwrap_or_else(|err| Host::resume_unwind(err.panic));
+            Caught::new(error, backtrace)
+        },
+    )
+}
+
+// Anything left in the exception slot was raised by an error caught outside of iex, e.g. by
+// catch_unwind, so it's cleared before the foreign panic is resumed
+#[cold]
+fn resume_foreign(payload: Payload) -> ! {
+    Host::with_exception(|exception| unsafe { &mut *exception.get() }.clear());
+    resume_foreign_panic(payload)
+}
+
+// The state of an error propagation that was in progress when a nested outcome started resolving,
+// e.g. if into_result() is called from a destructor during unwinding. Without saving it, a nested
+// error would overwrite the outer one. Restored on drop.
+struct SavedState {
+    exception: Exception,
+    #[cfg(all(feature = "realtime", debug_assertions))]
+    error_path_allocations: usize,
+}
+
+impl SavedState {
+    #[inline(always)]
+    fn save() -> Option<Self> {
+        if Host::with_exception(|exception| unsafe { &*exception.get() }.is_occupied()) {
+            Some(Self::save_cold())
+        } else {
+            None
+        }
+    }
+
+    #[cold]
+    fn save_cold() -> Self {
+        Self {
+            exception: Host::with_exception(|exception| unsafe { &mut *exception.get() }.take()),
+            #[cfg(all(feature = "realtime", debug_assertions))]
+            error_path_allocations: crate::realtime::take_error_path_allocations(),
+        }
+    }
+}
+
+impl Drop for SavedState {
+    fn drop(&mut self) {
+        Host::with_exception(|exception| unsafe { *exception.get() = self.exception.take() });
+        #[cfg(all(feature = "realtime", debug_assertions))]
+        crate::realtime::set_error_path_allocations(self.error_path_allocations);
+    }
+}
+
+#[cold]
+fn take_exception<E>() -> Result<E, ResolveError<E>> {
+    Host::with_exception(|exception| unsafe {
+        let exception = &mut *exception.get();
+        let result = match exception.take_secondary_panic() {
+            // The error may have been consumed by the panicking error handler
+            Some(panic) => Err(ResolveError {
+                original: exception.read(),
+                panic,
+            }),
+            None => Ok(exception.read_unchecked()),
+        };
+        exception.clear();
+        #[cfg(all(feature = "realtime", debug_assertions))]
+        crate::realtime::check_error_path();
+        result
+    })
+}
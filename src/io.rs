@@ -0,0 +1,42 @@
+//! [`#[iex]`](macro@iex) wrappers for [`std::io`] operations.
+//!
+//! These functions let I/O-heavy [`#[iex]`](macro@iex) code propagate [`io::Error`]s via unwinding,
+//! without converting each [`io::Result`] at the call site.
+
+use crate::iex;
+use alloc::vec::Vec;
+use std::io::{self, Read, Write};
+
+/// Read all bytes until EOF.
+///
+/// This is an [`#[iex]`](macro@iex) version of [`Read::read_to_end`] that returns the bytes in a
+/// new [`Vec`]. Reads interrupted with [`io::ErrorKind::Interrupted`] are retried.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, io::read_to_end_iex, Outcome};
+/// use std::io::Cursor;
+///
+/// #[iex]
+/// fn count_lines(input: &[u8]) -> Result<usize, std::io::Error> {
+///     let bytes = read_to_end_iex(Cursor::new(input))?;
+///     Ok(bytes.iter().filter(|&&b| b == b'\n').count())
+/// }
+///
+/// assert_eq!(count_lines(b"a\nb\n").into_result().unwrap(), 2);
+/// ```
+#[iex]
+pub fn read_to_end_iex<R: Read>(mut reader: R) -> Result<Vec<u8>, io::Error> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Write an entire buffer.
+///
+/// This is an [`#[iex]`](macro@iex) version of [`Write::write_all`].
+#[iex]
+pub fn write_all_iex<W: Write>(mut writer: W, buf: &[u8]) -> Result<(), io::Error> {
+    writer.write_all(buf)
+}
@@ -0,0 +1,506 @@
+use crate::{
+    iex,
+    iex_result::CallWithMarker,
+    imp::{IexResult, Marker},
+    Outcome,
+};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+
+/// Collect outcomes into a [`Vec`], reporting the position of the first failure.
+///
+/// The outcomes are resolved in order. If one of them fails, the error is returned alongside the
+/// zero-based index of the failing outcome, and the rest of the outcomes are not resolved.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, try_collect_indexed, Outcome};
+///
+/// #[iex]
+/// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// let result = try_collect_indexed(["1", "2", "x", "y"].map(parse)).into_result();
+/// assert_eq!(result.unwrap_err().0, 2);
+/// ```
+#[iex]
+pub fn try_collect_indexed<I>(
+    outcomes: I,
+) -> Result<Vec<<I::Item as Outcome>::Output>, (usize, <I::Item as Outcome>::Error)>
+where
+    I: IntoIterator,
+    I::Item: Outcome,
+{
+    let mut values = Vec::new();
+    for (index, outcome) in outcomes.into_iter().enumerate() {
+        values.push(outcome.map_err(|err| (index, err))?);
+    }
+    Ok(values)
+}
+
+/// Extension methods for iterators over outcomes.
+pub trait IexIteratorExt: Iterator {
+    /// Collect outcomes into a collection, stopping at the first error.
+    ///
+    /// This is an `#[iex]` counterpart of `Iterator::try_collect`. The outcomes are resolved in
+    /// order, without converting each of them to a [`Result`]: the first error unwinds out of the
+    /// collection loop, the values collected so far are dropped, and the rest of the outcomes are
+    /// not resolved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, IexIteratorExt, Outcome};
+    ///
+    /// #[iex]
+    /// fn checked_divide(a: u32, b: u32) -> Result<u32, &'static str> {
+    ///     a.checked_div(b).ok_or("Cannot divide by zero")
+    /// }
+    ///
+    /// #[iex]
+    /// fn checked_divide_by_many_numbers(a: u32, bs: &[u32]) -> Result<Vec<u32>, &'static str> {
+    ///     Ok(bs.iter().map(|&b| checked_divide(a, b)).try_collect_iex()?)
+    /// }
+    ///
+    /// assert_eq!(checked_divide_by_many_numbers(6, &[1, 2, 3]).into_result(), Ok(vec![6, 3, 2]));
+    /// assert!(checked_divide_by_many_numbers(6, &[1, 0]).into_result().is_err());
+    /// ```
+    #[cfg(doc)]
+    #[iex]
+    fn try_collect_iex<B, T, E>(self) -> Result<B, E>
+    where
+        Self: Sized,
+        Self::Item: Outcome<Output = T, Error = E>,
+        B: FromIterator<T>,
+    {
+    }
+
+    #[cfg(not(doc))]
+    fn try_collect_iex<B, T, E>(self) -> impl Outcome<Output = B, Error = E>
+    where
+        Self: Sized,
+        Self::Item: Outcome<Output = T, Error = E>,
+        B: FromIterator<T>,
+    {
+        IexResult(
+            move |marker: Marker<E>| {
+                self.map(|outcome| outcome.get_value_or_panic(marker))
+                    .collect()
+            },
+            PhantomData,
+        )
+    }
+}
+
+impl<I: Iterator> IexIteratorExt for I {}
+
+/// Concatenate the strings produced by outcomes.
+///
+/// The outcomes are resolved in order. If one of them fails, its error is returned, and the rest
+/// of the outcomes are not resolved. This is useful for templating, where each fragment of the
+/// output may fail to render.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, try_concat, Outcome};
+///
+/// #[iex]
+/// fn render(name: &str) -> Result<String, String> {
+///     match name {
+///         "greeting" => Ok("Hello".to_string()),
+///         "separator" => Ok(", ".to_string()),
+///         "world" => Ok("world!".to_string()),
+///         _ => Err(format!("Unknown fragment {name}")),
+///     }
+/// }
+///
+/// let fragments = ["greeting", "separator", "world"];
+/// assert_eq!(
+///     try_concat(fragments.map(render)).into_result(),
+///     Ok("Hello, world!".to_string()),
+/// );
+/// ```
+#[iex]
+pub fn try_concat<E, I>(fragments: I) -> Result<String, E>
+where
+    I: IntoIterator,
+    I::Item: Outcome<Output = String, Error = E>,
+{
+    let mut result = String::new();
+    for fragment in fragments {
+        result.push_str(&fragment?);
+    }
+    Ok(result)
+}
+
+/// Fold items into a mutable accumulator with a fallible function.
+///
+/// `f` is called for each item in order. If it fails, the error is returned, and the rest of the
+/// items are not processed. The accumulator is borrowed rather than moved in and out on each step,
+/// so on error, it holds the state after the last successful step.
+///
+/// An outcome returned by `f` may borrow the accumulator, which is always the case for
+/// [`#[iex]`](macro@crate::iex) functions. Due to limitations of closure type inference, such `f`
+/// must be a function rather than a closure. See [`TryFoldFn`] for more information.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, try_fold_into, Outcome};
+///
+/// #[iex]
+/// fn push_digit(number: &mut u32, digit: char) -> Result<(), String> {
+///     let digit = digit.to_digit(10).ok_or_else(|| format!("{digit:?} is not a digit"))?;
+///     *number = *number * 10 + digit;
+///     Ok(())
+/// }
+///
+/// let mut number = 0;
+/// let result = try_fold_into("12x3".chars(), &mut number, push_digit).into_result();
+/// assert_eq!(result, Err("'x' is not a digit".to_string()));
+/// assert_eq!(number, 12);
+/// ```
+#[iex]
+pub fn try_fold_into<A, I, E, F>(iter: I, acc: &mut A, mut f: F) -> Result<(), E>
+where
+    A: ?Sized,
+    I: IntoIterator,
+    F: for<'a> TryFoldFn<'a, A, I::Item, Error = E>,
+{
+    for item in iter {
+        f.call(acc, item)?;
+    }
+    Ok(())
+}
+
+/// Resolve all outcomes, collecting either all values or all errors.
+///
+/// Unlike [`try_collect_indexed`], this doesn't stop at the first error: every outcome is resolved,
+/// in order. If all of them succeed, their values are returned. Otherwise, the errors are returned
+/// in the order of the outcomes that produced them, and the values are dropped. This is useful for
+/// validation, where all problems should be reported at once.
+///
+/// Each outcome is resolved separately with [`into_result`](Outcome::into_result), so this function
+/// is off the fast path and is best used at the boundary where errors are reported.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, validate_all};
+///
+/// #[iex]
+/// fn check_field(name: &str, value: &str) -> Result<(), String> {
+///     if value.is_empty() {
+///         Err(format!("{name} must not be empty"))
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// let errors = validate_all([
+///     check_field("name", ""),
+///     check_field("email", "me@example.com"),
+///     check_field("password", ""),
+/// ])
+/// .unwrap_err();
+/// assert_eq!(errors, ["name must not be empty", "password must not be empty"]);
+/// ```
+pub fn validate_all<T, E, I>(outcomes: I) -> Result<Vec<T>, Vec<E>>
+where
+    I: IntoIterator,
+    I::Item: Outcome<Output = T, Error = E>,
+{
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+    for outcome in outcomes {
+        match outcome.into_result() {
+            Ok(value) if errors.is_empty() => values.push(value),
+            Ok(_) => {}
+            Err(err) => errors.push(err),
+        }
+    }
+    if errors.is_empty() {
+        Ok(values)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Return the value of the first successful outcome, or all errors if none succeeds.
+///
+/// The outcomes are resolved in order until one of them succeeds, and the rest of the outcomes are
+/// not resolved. This is useful for fallback chains, where each source is tried until one works.
+///
+/// Each outcome is resolved separately with [`into_result`](Outcome::into_result), so like
+/// [`validate_all`], this function is off the fast path.
+///
+/// # Example
+///
+/// ```
+/// use iex::{first_ok, iex, Outcome};
+///
+/// #[iex]
+/// fn read_env(name: &str) -> Result<String, String> {
+///     std::env::var(name).map_err(|_| format!("{name} is not set"))
+/// }
+///
+/// #[iex]
+/// fn default_port() -> Result<String, String> {
+///     Ok("8080".to_string())
+/// }
+///
+/// let port = first_ok([
+///     read_env("IEX_EXAMPLE_PORT").boxed(),
+///     read_env("IEX_EXAMPLE_FALLBACK_PORT").boxed(),
+///     default_port().boxed(),
+/// ]);
+/// assert_eq!(port, Ok("8080".to_string()));
+/// ```
+pub fn first_ok<T, E, I>(outcomes: I) -> Result<T, Vec<E>>
+where
+    I: IntoIterator,
+    I::Item: Outcome<Output = T, Error = E>,
+{
+    let mut errors = Vec::new();
+    let mut outcomes = outcomes.into_iter();
+    for outcome in outcomes.by_ref() {
+        match outcome.into_result() {
+            Ok(value) => {
+                crate::iex_result::discard(outcomes);
+                return Ok(value);
+            }
+            Err(err) => errors.push(err),
+        }
+    }
+    Err(errors)
+}
+
+/// Resolve outcomes lazily, yielding a [`Result`] for each of them.
+///
+/// Each call to `next` resolves one outcome with [`into_result`](Outcome::into_result). Unlike
+/// [`validate_all`], this leaves it to the caller to decide how to handle each error, e.g. to stop
+/// early, skip it, or report it and continue. Outcomes that are never reached are not resolved.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, results_iter};
+///
+/// #[iex]
+/// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// let mut sum = 0;
+/// let mut invalid = 0;
+/// for result in results_iter(["1", "x", "3"].map(parse)) {
+///     match result {
+///         Ok(value) => sum += value,
+///         Err(_) => invalid += 1,
+///     }
+/// }
+/// assert_eq!((sum, invalid), (4, 1));
+/// ```
+pub fn results_iter<T, E, I>(outcomes: I) -> impl Iterator<Item = Result<T, E>>
+where
+    I: IntoIterator,
+    I::Item: Outcome<Output = T, Error = E>,
+{
+    ResultsIter(ManuallyDrop::new(outcomes.into_iter()))
+}
+
+struct ResultsIter<I>(ManuallyDrop<I>);
+
+impl<I: Iterator> Iterator for ResultsIter<I>
+where
+    I::Item: Outcome,
+{
+    type Item = Result<<I::Item as Outcome>::Output, <I::Item as Outcome>::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Outcome::into_result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<I> Drop for ResultsIter<I> {
+    fn drop(&mut self) {
+        // The caller may stop iterating early, leaving the rest of the outcomes unresolved
+        crate::iex_result::discard(unsafe { ManuallyDrop::take(&mut self.0) });
+    }
+}
+
+/// Split outcomes into chunks and collect each chunk into a [`Vec`].
+///
+/// Returns an iterator of outcomes, each resolving up to `N` outcomes from `outcomes` in order. If
+/// one of them fails, the chunk fails with its error, and the rest of the outcomes in the chunk are
+/// not resolved. The following chunks are unaffected, so iteration can continue after an error.
+///
+/// This bounds memory usage when processing large fallible streams: only one chunk of values is
+/// kept at a time.
+///
+/// # Panics
+///
+/// Panics if `N` is 0.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, try_collect_chunks, Outcome};
+///
+/// #[iex]
+/// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// let mut chunks = try_collect_chunks::<_, _, 2, _>(["1", "2", "3", "x", "5"].map(parse));
+/// assert_eq!(chunks.next().unwrap().into_result(), Ok(vec![1, 2]));
+/// assert!(chunks.next().unwrap().into_result().is_err());
+/// assert_eq!(chunks.next().unwrap().into_result(), Ok(vec![5]));
+/// assert!(chunks.next().is_none());
+/// ```
+pub fn try_collect_chunks<T, E, const N: usize, I>(
+    outcomes: I,
+) -> impl Iterator<Item = impl Outcome<Output = Vec<T>, Error = E>>
+where
+    I: IntoIterator,
+    I::Item: Outcome<Output = T, Error = E>,
+{
+    assert!(N > 0, "chunk size must be non-zero");
+    let mut outcomes = ChunksSource(ManuallyDrop::new(outcomes.into_iter()));
+    core::iter::from_fn(move || {
+        let chunk: Vec<I::Item> = outcomes.0.by_ref().take(N).collect();
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(collect_chunk(chunk))
+        }
+    })
+}
+
+struct ChunksSource<I>(ManuallyDrop<I>);
+
+impl<I> Drop for ChunksSource<I> {
+    fn drop(&mut self) {
+        // The caller may stop iterating early, leaving the rest of the outcomes unresolved
+        crate::iex_result::discard(unsafe { ManuallyDrop::take(&mut self.0) });
+    }
+}
+
+#[iex]
+fn collect_chunk<R: Outcome>(chunk: Vec<R>) -> Result<Vec<R::Output>, R::Error> {
+    let mut values = Vec::with_capacity(chunk.len());
+    for outcome in chunk {
+        values.push(outcome?);
+    }
+    Ok(values)
+}
+
+/// A step function for [`try_fold_into`].
+///
+/// This trait is implemented for all `FnMut(&'a mut A, T) -> R`, where `R` is an outcome with `()`
+/// output. It exists because the type of the outcome may depend on `'a`, which can't be expressed
+/// with an `FnMut` bound.
+///
+/// Closures can only implement this trait for all `'a` if their argument types are specified
+/// explicitly and the outcome they return doesn't borrow the accumulator. Use functions otherwise.
+pub trait TryFoldFn<'a, A: ?Sized + 'a, T> {
+    /// The error type of the step.
+    type Error;
+
+    /// The outcome returned by the step.
+    type Outcome: Outcome<Output = (), Error = Self::Error>;
+
+    /// Call the step function.
+    fn call(&mut self, acc: &'a mut A, item: T) -> Self::Outcome;
+}
+
+impl<'a, A: ?Sized + 'a, T, R, F> TryFoldFn<'a, A, T> for F
+where
+    R: Outcome<Output = ()>,
+    F: FnMut(&'a mut A, T) -> R,
+{
+    type Error = R::Error;
+    type Outcome = R;
+
+    fn call(&mut self, acc: &'a mut A, item: T) -> R {
+        self(acc, item)
+    }
+}
+
+pub(crate) struct FlatMapIter<R, F, I: IntoIterator> {
+    state: FlatMapState<R, F, I::IntoIter>,
+}
+
+enum FlatMapState<R, F, It> {
+    Pending(R, F),
+    Running(It),
+    Done,
+}
+
+impl<R, F, I: IntoIterator> FlatMapIter<R, F, I> {
+    pub(crate) fn new(outcome: R, f: F) -> Self {
+        Self {
+            state: FlatMapState::Pending(outcome, f),
+        }
+    }
+}
+
+impl<R, F, I> Iterator for FlatMapIter<R, F, I>
+where
+    R: Outcome,
+    F: FnOnce(R::Output) -> I,
+    I: IntoIterator,
+    I::Item: Outcome<Error = R::Error>,
+{
+    type Item = IexResult<<I::Item as Outcome>::Output, R::Error, FlatMapItem<I::Item>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = match core::mem::replace(&mut self.state, FlatMapState::Done) {
+            FlatMapState::Pending(outcome, f) => match outcome.into_result() {
+                Ok(value) => {
+                    self.state = FlatMapState::Running(f(value).into_iter());
+                    return self.next();
+                }
+                Err(err) => FlatMapItem::Outer(err),
+            },
+            FlatMapState::Running(mut iter) => {
+                let item = iter.next()?;
+                self.state = FlatMapState::Running(iter);
+                FlatMapItem::Inner(item)
+            }
+            FlatMapState::Done => return None,
+        };
+        Some(IexResult(item, PhantomData))
+    }
+}
+
+impl<R, F, I: IntoIterator> Drop for FlatMapIter<R, F, I> {
+    fn drop(&mut self) {
+        // The caller may stop iterating early, leaving the outer outcome or the rest of the inner
+        // outcomes unresolved
+        crate::iex_result::discard(core::mem::replace(&mut self.state, FlatMapState::Done));
+    }
+}
+
+pub(crate) enum FlatMapItem<R: Outcome> {
+    Inner(R),
+    Outer(R::Error),
+}
+
+impl<R: Outcome> CallWithMarker<R::Output, R::Error> for FlatMapItem<R> {
+    fn call_with_marker(self, marker: Marker<R::Error>) -> R::Output {
+        match self {
+            Self::Inner(outcome) => outcome.get_value_or_panic(marker),
+            Self::Outer(err) => Err::<R::Output, _>(err).get_value_or_panic(marker),
+        }
+    }
+}
@@ -130,30 +130,132 @@
 //!
 //! Doing anything else to the return value, e.g. storing it in a variable and using it later will
 //! not cause UB, but will not work the way you think either. If you want to swallow the error, use
-//! `let _ = func().into_result();` instead.
+//! `let _ = func().into_result();` instead. To catch such bugs, enable the `check-unresolved`
+//! feature: debug builds then panic when an `#[iex] Result` is dropped without being resolved,
+//! since the function is never called in that case. Dropping one on purpose, e.g. in
+//! `drop(func().boxed())`, is valid, which is why this is opt-in.
+//!
+//! Errors are propagated by unwinding with a payload of a private type, so they can't be confused
+//! with the sentinels of other libraries built on `catch_unwind`, or with the errors of another
+//! major version of iex. Conversely, [`.into_result()`](Outcome::into_result) and other catch sites
+//! resume foreign panics with their original payloads.
+//!
+//! An `#[iex] Result` only stores the arguments of the call, so its size doesn't depend on `T` or
+//! `E`. For example, calling a function that takes a single `u32` produces a 4-byte outcome, even
+//! if the error type is huge. Combinators like [`map_err`](Outcome::map_err) additionally store
+//! their closures.
 //!
 //! Directly returning an `#[iex] Result` (obtained from a function call) from another
 //! [`#[iex]`](macro@iex) function also works, provided that it's the only `return` statement in the
-//! function. Use `Ok(..?)` if there are multiple returns.
+//! function. Use `Ok(..?)` if there are multiple returns. Returning a plain [`Result`], e.g.
+//! `s.parse().map_err(..)`, works regardless of the number of returns.
 //!
 //! [`#[iex]`](macro@iex) works on methods. If applied to a function in an `impl Trait for Type`
 //! block, the corresponding function in the `trait Trait` block should also be marked with
 //! [`#[iex]`](macro@iex). Such traits are not object-safe, unless the method is restricted to
-//! `where Self: Sized` (open an issue if you want me to spend time developing a workaround).
+//! `where Self: Sized` or marked with [`#[iex(boxed)]`](macro@iex#iexboxed), which allocates on
+//! each call.
+//!
+//! # `no_std`
+//!
+//! iex can be used without `std` (but with `alloc`) by disabling the default `std` feature. Errors
+//! are still propagated by unwinding, so the target must support unwinding and the binary must be
+//! built with `panic = "unwind"`. `core` cannot catch or resume unwinding, so the platform has to
+//! implement the `UnwindShim` trait and register it with `iex::unwind_shim!`. There is no
+//! thread-local storage in `core` either, so in this mode iex must only be used from a single
+//! thread.
+//!
+//! Threading and I/O helpers, panic hooks, and the `anyhow`, `eyre`, `backtrace`,
+//! `check-unresolved`, `rayon` and `realtime` features require `std`.
+//!
+//! # Plain mode
+//!
+//! When a bug is suspected to be caused by iex itself, or a debugger has trouble following
+//! unwinding, enable the `plain` feature. [`#[iex]`](macro@iex) functions then compile to ordinary
+//! functions returning [`Result`], `?` propagates errors through the return value, and
+//! [`Outcome`] methods operate on real [`Result`]s. No code changes are needed, but note that:
+//! - `?` converts errors with [`From`] like it normally does, rather than [`Into`],
+//! - `#[iex] Result`s are evaluated eagerly, so e.g. dropping one without resolving it still calls
+//!   the function,
+//! - Errors are returned rather than raised, so no backtrace is recorded for `last_backtrace`, and
+//!   panics never pass through iex: the foreign panic hook is not called, and
+//!   [`into_result_preserving_panic`](Outcome::into_result_preserving_panic) resumes panics in
+//!   error handlers,
+//! - Helpers that take closures, like [`catch`] or [`try_collect_iex`](IexIteratorExt), keep using
+//!   the exception machinery internally.
+//!
+//! The generated documentation is the same in both modes.
 
-#![cfg_attr(doc, feature(doc_auto_cfg))]
+#![cfg_attr(doc, feature(doc_cfg))]
+#![cfg_attr(feature = "nightly", feature(try_trait_v2))]
+#![no_std]
+// Most of the exception machinery is unused with the `plain` feature
+#![cfg_attr(feature = "plain", allow(dead_code, unused_imports))]
 
-mod macros;
-pub use macros::{iex, try_block};
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-use std::cell::UnsafeCell;
+mod macros;
+pub use macros::{iex, iex_closure, try_block};
 
 mod exception;
-use exception::Exception;
+
+mod platform;
+#[cfg(not(feature = "std"))]
+pub use platform::UnwindShim;
+use platform::{Host, Payload, Platform};
 
 mod outcome;
 pub use outcome::Outcome;
 
+mod outcome_option;
+pub use outcome_option::OutcomeOption;
+
+mod any_outcome;
+pub use any_outcome::AnyOutcome;
+
+mod option;
+pub use option::{transpose_option, OptionIexExt};
+
+mod iter;
+pub use iter::{
+    first_ok, results_iter, try_collect_chunks, try_collect_indexed, try_concat, try_fold_into,
+    validate_all, IexIteratorExt, TryFoldFn,
+};
+
+mod resolve_error;
+pub use resolve_error::ResolveError;
+
+#[cfg(feature = "backtrace")]
+mod caught;
+#[cfg(feature = "backtrace")]
+pub use caught::Caught;
+
+mod wrap_error;
+pub use wrap_error::{ContextError, WrapError};
+
+#[cfg(feature = "std")]
+mod thread;
+#[cfg(feature = "rayon")]
+pub use thread::par_map_collect;
+#[cfg(feature = "std")]
+pub use thread::{send_results, spawn_scoped_resolve};
+
+mod panic_hook;
+#[cfg(feature = "std")]
+pub use panic_hook::set_foreign_panic_hook;
+
+#[cfg(feature = "nightly")]
+mod catch_into;
+#[cfg(feature = "nightly")]
+pub use catch_into::ErrorResidual;
+
+#[cfg(feature = "realtime")]
+mod realtime;
+#[cfg(feature = "realtime")]
+pub use realtime::CountingAllocator;
+
 #[cfg(feature = "anyhow")]
 mod anyhow_compat;
 #[cfg(feature = "anyhow")]
@@ -166,9 +268,23 @@ impl<T, E> Context<T, E> for Result<T, E> {}
 #[cfg(not(feature = "anyhow"))]
 impl<T, E, Func: iex_result::CallWithMarker<T, E>> Context<T, E> for imp::IexResult<T, E, Func> {}
 #[cfg(not(feature = "anyhow"))]
-impl<T> Context<T, std::convert::Infallible> for Option<T> {}
+impl<T> Context<T, core::convert::Infallible> for Option<T> {}
+
+#[cfg(feature = "eyre")]
+mod eyre_compat;
+#[cfg(feature = "eyre")]
+pub use eyre_compat::WrapErr;
+
+#[cfg(not(feature = "eyre"))]
+pub trait WrapErr<T, E> {}
+#[cfg(not(feature = "eyre"))]
+impl<T, E> WrapErr<T, E> for Result<T, E> {}
+#[cfg(not(feature = "eyre"))]
+impl<T, E, Func: iex_result::CallWithMarker<T, E>> WrapErr<T, E> for imp::IexResult<T, E, Func> {}
 
+mod combinator;
 mod iex_result;
+pub use iex_result::{catch, DynOutcome};
 mod result;
 
 mod exception_mapper;
@@ -177,10 +293,111 @@ mod marker;
 
 pub mod example;
 
+#[cfg(feature = "std")]
+pub mod io;
+
 struct IexPanic;
 
-thread_local! {
-    static EXCEPTION: UnsafeCell<Exception> = const { UnsafeCell::new(Exception::new()) };
+#[cfg(feature = "backtrace")]
+std::thread_local! {
+    // The backtrace of the error most recently caught by an outermost into_result() and friends
+    static LAST_BACKTRACE: core::cell::Cell<Option<std::backtrace::Backtrace>> = const {
+        core::cell::Cell::new(None)
+    };
+}
+
+#[cfg(feature = "backtrace")]
+std::thread_local! {
+    // The number of outcomes being resolved. Errors caught while resolving another outcome, e.g. by
+    // or_else(), are usually recovered from, so they don't replace LAST_BACKTRACE.
+    static RESOLVE_DEPTH: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+}
+
+#[cfg(feature = "backtrace")]
+struct ResolveScope {
+    outermost: bool,
+}
+
+#[cfg(feature = "backtrace")]
+impl ResolveScope {
+    fn enter() -> Self {
+        let depth = RESOLVE_DEPTH.get();
+        RESOLVE_DEPTH.set(depth + 1);
+        Self {
+            outermost: depth == 0,
+        }
+    }
+
+    fn caught(&self, backtrace: Option<std::backtrace::Backtrace>) {
+        if self.outermost {
+            LAST_BACKTRACE.set(backtrace);
+        }
+    }
+}
+
+#[cfg(feature = "backtrace")]
+impl Drop for ResolveScope {
+    fn drop(&mut self) {
+        RESOLVE_DEPTH.set(RESOLVE_DEPTH.get() - 1);
+    }
+}
+
+// Take the backtrace of the error being propagated, if any
+#[cfg(feature = "backtrace")]
+fn take_backtrace() -> Option<std::backtrace::Backtrace> {
+    Host::with_exception(|exception| unsafe { &mut *exception.get() }.take_backtrace())
+}
+
+/// Take the backtrace of the point where the last caught error was raised.
+///
+/// When an `#[iex]` function raises an error, the backtrace is captured with
+/// [`Backtrace::capture`](std::backtrace::Backtrace::capture), so it is only populated if enabled
+/// by the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables. Once the error is caught,
+/// e.g. by [`into_result`](Outcome::into_result), the backtrace can be retrieved from the same
+/// thread by calling this function.
+///
+/// Only errors caught by an outermost resolution are recorded. Errors caught while another outcome
+/// is being resolved, e.g. by [`or_else`](Outcome::or_else) or by [`unwrap_or`](Outcome::unwrap_or)
+/// inside an `#[iex]` function, are usually recovered from and don't replace the backtrace. Use
+/// [`into_result_with_backtrace`](Outcome::into_result_with_backtrace) to get the backtrace of a
+/// specific error instead.
+///
+/// Returns `None` if no error was caught on this thread since the last call, or if the backtrace
+/// was consumed by [`map_err_backtrace`](Outcome::map_err_backtrace) during propagation. Errors
+/// that never propagated by unwinding, e.g. calling `into_result()` on a [`Result`], don't have a
+/// backtrace either.
+///
+/// # Example
+///
+#[cfg_attr(not(feature = "plain"), doc = " ```")]
+#[cfg_attr(feature = "plain", doc = " ```ignore")]
+/// use iex::{iex, Outcome};
+///
+/// #[iex]
+/// fn fails() -> Result<(), &'static str> {
+///     Err("Failed")
+/// }
+///
+/// #[iex]
+/// fn propagates() -> Result<(), &'static str> {
+///     fails()?;
+///     Ok(())
+/// }
+///
+/// assert_eq!(propagates().into_result(), Err("Failed"));
+/// let backtrace = iex::last_backtrace().unwrap();
+/// println!("Raised at:\n{backtrace}");
+/// assert!(iex::last_backtrace().is_none());
+/// ```
+#[cfg(feature = "backtrace")]
+pub fn last_backtrace() -> Option<std::backtrace::Backtrace> {
+    LAST_BACKTRACE.take()
+}
+
+fn stash_secondary_panic(payload: Payload) {
+    Host::with_exception(|exception| {
+        unsafe { &mut *exception.get() }.stash_secondary_panic(payload)
+    });
 }
 
 #[doc(hidden)]
@@ -188,10 +405,23 @@ pub mod imp {
     use super::*;
     pub use exception_mapper::ExceptionMapper;
     pub use fix_hidden_lifetime_bug;
-    pub use forward::_IexForward;
-    pub use iex_result::IexResult;
+    pub use forward::{
+        _IexForward, _IexForwardResolved, _IexOutcomeInScope, _IexOutcomeProbe, _IexResolved,
+    };
+    pub use iex_result::{IexResult, NoRaise};
     pub use marker::Marker;
+    pub use outcome_option::{_iex_forward_option, IexOption, NoneError};
+    pub use platform::Payload;
     pub struct NoCopy;
+
+    #[macro_export]
+    #[doc(hidden)]
+    macro_rules! __iex_identity_type {
+        ($ty:ty) => {
+            $ty
+        };
+    }
+    pub use __iex_identity_type as identity_type;
 }
 
 extern crate self as iex;
@@ -11,6 +11,17 @@
 /// opposed to the built-in try operator) that propagates the error from a [`Result<T, E>`] or an
 /// `#[iex] Result<T, E>` and returns a `T`.
 ///
+/// As both [`Result<T, E>`] and `#[iex] Result<T, E>` implement [`Outcome`](crate::Outcome), the
+/// attribute can be applied conditionally, e.g. with `#[cfg_attr(feature = "fast", iex)]`, without
+/// changing the call sites.
+///
+/// Functions returning [`Option<T>`] are turned into functions returning `#[iex] Option<T>`, which
+/// implements [`OutcomeOption`](crate::OutcomeOption) and propagates `None` the same way.
+///
+/// The return type doesn't have to be spelled as `Result` literally. The output and error types
+/// are extracted via [`Outcome`](crate::Outcome), so type aliases work, including aliases whose
+/// definition depends on `cfg`.
+///
 /// **Closure support is incomplete and nightly-only.**
 ///
 /// # Pitfalls
@@ -72,26 +83,6 @@
 ///    | |_____^
 /// ```
 ///
-/// Finally, make sure to use the same lifetimes in `trait` and `impl`:
-///
-/// ```compile_fail
-/// use iex::iex;
-///
-/// trait Trait {
-///     #[iex]
-///     fn takes_str(s: &'static str) -> Result<(), ()>;
-/// }
-///
-/// impl Trait for () {
-///     // error[E0308]: method not compatible with trait
-///     // Use 's: &'static str' instead
-///     #[iex]
-///     fn takes_str(s: &str) -> Result<(), ()> {
-///         Ok(())
-///     }
-/// }
-/// ```
-///
 /// ## Closures
 ///
 /// `#[iex]` closures can't take arguments whose types contain non-`'static` lifetimes. Sorry. Also,
@@ -104,7 +95,8 @@
 /// `#[iex]` needs to replace the `?` operator with a custom implementation in the function body.
 /// This notably fails if the `?` is generated by a macro:
 ///
-/// ```compile_fail
+#[cfg_attr(not(feature = "plain"), doc = " ```compile_fail")]
+#[cfg_attr(feature = "plain", doc = " ```ignore")]
 /// use iex::iex;
 ///
 /// #[iex]
@@ -123,6 +115,69 @@
 /// }
 /// ```
 ///
+/// ## `?` on `Option`
+///
+/// Like the built-in `?`, the `?` in a function returning a [`Result`] can't be applied to an
+/// [`Option`]. Convert it with [`ok_or`](Option::ok_or) first:
+///
+/// ```
+/// use iex::{iex, Outcome};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum ConfigError {
+///     Missing,
+/// }
+///
+/// #[iex]
+/// fn first_port(ports: &[u16]) -> Result<u16, ConfigError> {
+///     Ok(*ports.first().ok_or(ConfigError::Missing)?)
+/// }
+///
+/// assert_eq!(first_port(&[80]).into_result(), Ok(80));
+/// assert_eq!(first_port(&[]).into_result(), Err(ConfigError::Missing));
+/// ```
+///
+/// On `Some`, `?` evaluates to the value. On `None`, it writes the error to a thread-local slot,
+/// boxing it if it doesn't fit inline, and starts unwinding with a zero-sized payload. The nearest
+/// [`into_result`](crate::Outcome::into_result) catches the unwinding, checks that the payload
+/// belongs to `iex`, and takes the error out of the slot. Use [`ok_or_else`](Option::ok_or_else)
+/// or [`ok_or_iex`](crate::OptionIexExt::ok_or_iex) if the error is expensive to construct.
+///
+/// # Async functions
+///
+/// `#[iex]` can be applied to `async fn`. The returned future resolves to an `#[iex] Result`, and
+/// `?` works on both `#[iex] Result` and [`Result`] in the body:
+///
+/// ```
+/// use iex::{iex, Outcome};
+///
+/// #[iex]
+/// fn parse_url(url: &str) -> Result<&str, String> {
+///     url.strip_prefix("http://").ok_or_else(|| format!("Invalid URL {url}"))
+/// }
+///
+/// #[iex]
+/// async fn fetch(url: &str) -> Result<Vec<u8>, String> {
+///     let path = parse_url(url)?;
+///     // ...
+///     # Ok(path.as_bytes().to_vec())
+/// }
+///
+/// #[iex]
+/// async fn fetch_len(url: &str) -> Result<usize, String> {
+///     Ok(fetch(url).await?.len())
+/// }
+/// ```
+///
+/// Errors can't be propagated by unwinding across `.await`, as the future might be resumed on a
+/// different thread. Instead, `?` resolves the outcome via
+/// [`into_result`](crate::Outcome::into_result) before continuing, so the happy path of an async
+/// function isn't faster than with [`Result`]. This makes `#[iex] async fn` safe to use with any
+/// executor, including work-stealing ones.
+///
+/// Unlike in synchronous functions, returning an `#[iex] Result` directly is not supported. Use
+/// `Ok(..?)` instead. Async functions in traits are not supported either.
+///
 /// # Attributes
 ///
 /// Rust evaluates attribute macros from top to bottom, so if `#[iex]` is not the only attribute
@@ -131,7 +186,45 @@
 /// generated by `#[iex]`.
 ///
 /// Note that this only applies to attribute *macros*; normal attributes, such as `#[inline]` and
-/// `#[cfg]`, do the right thing independently from their location.
+/// `#[cfg]`, do the right thing independently from their location. Lint attributes, such as
+/// `#[allow(unused_variables)]`, apply to both the arguments and the body. In particular,
+/// `#[deprecated]` applies to the function, so its callers are warned:
+///
+/// ```compile_fail
+/// #![deny(deprecated)]
+/// use iex::{iex, Outcome};
+///
+/// #[deprecated = "use `new_api` instead"]
+/// #[iex]
+/// fn old_api() -> Result<(), ()> {
+///     Ok(())
+/// }
+///
+/// fn main() {
+///     // error: use of deprecated function `old_api`: use `new_api` instead
+///     let _ = old_api().into_result();
+/// }
+/// ```
+///
+/// Similarly, a custom message in `#[must_use = ".."]` is shown when the outcome is left unused,
+/// in addition to the generic warning about unused outcomes:
+///
+/// ```no_run
+/// use iex::iex;
+///
+/// #[must_use = "the connection is closed when dropped"]
+/// #[iex]
+/// fn connect() -> Result<(), ()> {
+///     Ok(())
+/// }
+///
+/// #[allow(unused_must_use)]
+/// fn main() {
+///     // warning: unused return value of `connect` that must be used
+///     //   = note: the connection is closed when dropped
+///     connect();
+/// }
+/// ```
 ///
 /// # Documentation
 ///
@@ -142,6 +235,99 @@
 ///
 /// For a rendered example, see [`example`](crate::example).
 ///
+/// # `#[iex(also_result = ..)]`
+///
+/// Libraries may want to offer a plain [`Result`] API alongside the `#[iex]` one.
+/// `#[iex(also_result = "name")]` generates a sibling function called `name` with the same
+/// signature that returns a plain [`Result`], as if by calling
+/// [`.into_result()`](crate::Outcome::into_result):
+///
+/// ```
+/// use iex::{iex, Outcome};
+///
+/// #[iex(also_result = "parse_port_result")]
+/// pub fn parse_port(s: &str) -> Result<u16, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// assert_eq!(parse_port("80").into_result(), Ok(80));
+/// assert!(parse_port_result("x").is_err());
+/// ```
+///
+/// The body is compiled separately for both functions.
+///
+/// # `#[iex(boxed)]`
+///
+/// `#[iex]` methods return `impl Outcome`, so traits containing them are not object-safe.
+/// `#[iex(boxed)]` makes the method return a [`DynOutcome`](crate::DynOutcome) instead, which
+/// keeps propagating errors by unwinding, but can be returned from a trait object. Both the method
+/// in the trait and its implementations have to be marked with `#[iex(boxed)]`:
+///
+/// ```
+/// use iex::{iex, Outcome};
+///
+/// trait Handler {
+///     #[iex(boxed)]
+///     fn handle(&self, request: &str) -> Result<u32, String>;
+/// }
+///
+/// struct Parse;
+///
+/// impl Handler for Parse {
+///     #[iex(boxed)]
+///     fn handle(&self, request: &str) -> Result<u32, String> {
+///         request.parse().map_err(|_| format!("not a number: {request}"))
+///     }
+/// }
+///
+/// #[iex]
+/// fn handle_all(handlers: &[Box<dyn Handler>], request: &str) -> Result<u32, String> {
+///     let mut total = 0;
+///     for handler in handlers {
+///         total += handler.handle(request)?;
+///     }
+///     Ok(total)
+/// }
+///
+/// let handlers: Vec<Box<dyn Handler>> = vec![Box::new(Parse), Box::new(Parse)];
+/// assert_eq!(handle_all(&handlers, "2").into_result(), Ok(4));
+/// assert!(handle_all(&handlers, "x").into_result().is_err());
+/// ```
+///
+/// This is not free: each call allocates a box for the arguments, and the body can't be inlined
+/// into the caller, as it's invoked through a function pointer. Errors are raised and propagated
+/// as usual. Use it where dynamic dispatch is needed anyway, and prefer generics elsewhere.
+///
+/// The box borrows all the arguments, so the elided lifetimes in argument types are named by the
+/// macro. Lifetimes hidden in paths, such as in `Wrapper<u8>` for `struct Wrapper<'a, T>`, have to
+/// be written out as `Wrapper<'_, u8>`.
+///
+/// # `#[iex(main)]`
+///
+/// `fn main` has to return a type implementing [`Termination`](std::process::Termination), which
+/// an `#[iex] Result` doesn't. `#[iex(main)]` keeps the function returning a plain [`Result`]: the
+/// body is compiled as usual, and its outcome is resolved right away. Errors are reported by the
+/// standard library, i.e. printed to stderr, and the process exits with a non-zero code.
+///
+/// ```no_run
+/// use iex::iex;
+///
+/// #[iex]
+/// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// #[iex(main)]
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let value = parse("12")?;
+///     println!("{value}");
+///     Ok(())
+/// }
+/// ```
+///
+/// This is opt-in: the macro can't tell the crate's entry point from other functions called
+/// `main`, such as associated functions, so a plain `#[iex]` leaves all of them lazy.
+///
 /// # `#[iex(shares = ..)]`
 ///
 /// This use is specific for `map_err` and `inspect_err`. See the documentation for
@@ -149,7 +335,8 @@
 ///
 /// # Example
 ///
-/// ```
+#[cfg_attr(not(feature = "plain"), doc = " ```")]
+#[cfg_attr(feature = "plain", doc = " ```ignore")]
 /// #![feature(stmt_expr_attributes, proc_macro_hygiene)]
 /// // The Outcome trait is required for .into_result()
 /// use iex::{iex, Outcome};
@@ -185,25 +372,79 @@
 /// }
 /// ```
 ///
-/// This attribute can only be applied to functions that return a [`Result`]:
+/// This attribute can only be applied to functions that return a [`Result`] or an [`Option`]. An
+/// [`Option`] is recognized by a path ending in `Option<T>`, so it can't be hidden behind a type
+/// alias. Any other return type is validated by the [`Outcome`](crate::Outcome) trait instead of
+/// syntactically, so paths like `core::result::Result` and type aliases like `anyhow::Result<T>`
+/// work too:
+///
+/// ```
+/// use iex::iex;
+///
+/// type MyResult<T> = Result<T, String>;
+///
+/// #[iex]
+/// fn alias() -> MyResult<i32> {
+///     Ok(1)
+/// }
+///
+/// #[iex]
+/// fn qualified() -> core::result::Result<i32, String> {
+///     Ok(alias()? + 1)
+/// }
+/// ```
+///
+/// Other return types are rejected:
 ///
 /// ```compile_fail
 /// # use iex::iex;
-/// // the trait `Outcome` is not implemented for `Option<()>`
+/// // `bool` is not a `Result` or an `Option`
 /// #[iex]
-/// fn invalid_example() -> Option<()> {
-///     None
+/// fn invalid_example() -> bool {
+///     false
 /// }
 /// ```
 ///
 /// ```compile_fail
 /// # use iex::iex;
-/// // the trait `Outcome` is not implemented for `()`
+/// // `()` is not a `Result` or an `Option`
 /// #[iex]
 /// fn invalid_example() {}
 /// ```
 pub use iex_derive::iex;
 
+/// `#[iex]` closure.
+///
+/// `iex_closure!(|args| -> Result<T, E> { .. })` is equivalent to applying
+/// [`#[iex]`](macro@crate::iex) to the closure, but works on stable Rust, where attributes on
+/// expressions are not allowed. The closure returns an `#[iex] Result`, and `?` in its body raises
+/// errors just like in `#[iex]` functions. The limitations of `#[iex]` closures apply: argument types
+/// can't contain non-`'static` lifetimes. Captured variables, however, can borrow locals. Captures
+/// are moved into the body when it's called, so a closure capturing non-`Copy` values by value can
+/// only be called once.
+///
+/// This macro can't be called `iex!`, as attribute and function-like macros share a namespace.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, iex_closure, Outcome};
+///
+/// #[iex]
+/// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// let offset = String::from("10");
+/// let offset = &offset;
+/// let add_offset = iex_closure!(|s: &'static str| -> Result<u32, std::num::ParseIntError> {
+///     Ok(parse(s)? + parse(offset)?)
+/// });
+/// assert_eq!(add_offset("5").into_result(), Ok(15));
+/// assert!(add_offset("x").into_result().is_err());
+/// ```
+pub use iex_derive::iex_closure;
+
 /// Try block.
 ///
 /// This is an implementation of the [nightly `try` blocks][1] for [`#[iex]`](macro@crate::iex).
@@ -232,3 +473,257 @@ pub use iex_derive::iex;
 ///
 /// [1]: https://doc.rust-lang.org/nightly/unstable-book/language-features/try-blocks.html
 pub use iex_derive::try_block;
+
+/// Resolve an outcome, or `continue` the enclosing loop on error.
+///
+/// This is useful for skipping invalid items in a loop while keeping the valid ones. The error is
+/// dropped. A loop label can be passed as the second argument to continue an outer loop.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, ok_or_continue};
+///
+/// #[iex]
+/// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// let mut numbers = Vec::new();
+/// for s in ["1", "x", "3"] {
+///     numbers.push(ok_or_continue!(parse(s)));
+/// }
+/// assert_eq!(numbers, [1, 3]);
+///
+/// let mut rows = Vec::new();
+/// 'rows: for row in [["1", "2"], ["3", "x"]] {
+///     let mut values = Vec::new();
+///     for s in row {
+///         values.push(ok_or_continue!(parse(s), 'rows));
+///     }
+///     rows.push(values);
+/// }
+/// assert_eq!(rows, [[1, 2]]);
+/// ```
+#[macro_export]
+macro_rules! ok_or_continue {
+    ($outcome:expr) => {
+        match $crate::Outcome::into_result($outcome) {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(_) => continue,
+        }
+    };
+    ($outcome:expr, $label:lifetime) => {
+        match $crate::Outcome::into_result($outcome) {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(_) => continue $label,
+        }
+    };
+}
+
+/// Resolve an outcome, handle some errors, and propagate the rest.
+///
+/// On success, evaluates to the value. If the error matches one of the arms, evaluates to the arm's
+/// handler. Otherwise, the error is returned from the enclosing function after conversion via
+/// [`From`], just like `?` does, so unmatched errors propagate through [`#[iex]`](macro@crate::iex)
+/// functions as usual.
+///
+/// Like in any other macro invocation, `?` inside the handlers is not rewritten by
+/// [`#[iex]`](macro@crate::iex). Use `.into_result()?` to propagate errors from handlers.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, match_err, Outcome};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum FetchError {
+///     NotFound,
+///     Timeout(u32),
+/// }
+///
+/// #[iex]
+/// fn fetch(key: &str) -> Result<String, FetchError> {
+///     match key {
+///         "missing" => Err(FetchError::NotFound),
+///         "slow" => Err(FetchError::Timeout(30)),
+///         _ => Ok(key.to_uppercase()),
+///     }
+/// }
+///
+/// #[iex]
+/// fn fetch_or_default(key: &str) -> Result<String, FetchError> {
+///     let value = match_err!(fetch(key), {
+///         FetchError::NotFound => String::new(),
+///     });
+///     Ok(value)
+/// }
+///
+/// assert_eq!(fetch_or_default("key").into_result(), Ok("KEY".to_string()));
+/// assert_eq!(fetch_or_default("missing").into_result(), Ok(String::new()));
+/// assert_eq!(fetch_or_default("slow").into_result(), Err(FetchError::Timeout(30)));
+/// ```
+#[macro_export]
+macro_rules! match_err {
+    ($outcome:expr, { $($pattern:pat $(if $guard:expr)? => $handler:expr),* $(,)? }) => {
+        match $crate::Outcome::into_result($outcome) {
+            ::core::result::Result::Ok(value) => value,
+            $(::core::result::Result::Err($pattern) $(if $guard)? => $handler,)*
+            #[allow(unreachable_patterns)]
+            ::core::result::Result::Err(err) => {
+                return ::core::result::Result::Err(::core::convert::From::from(err));
+            }
+        }
+    };
+}
+
+/// Adapt a function returning an [`Outcome`](crate::Outcome) to return a [`Result`].
+///
+/// `as_result_fn!(f, n)` evaluates to a closure taking `n` arguments that calls `f` and resolves
+/// the returned outcome via [`into_result`](crate::Outcome::into_result). This lets you pass
+/// [`#[iex]`](macro@crate::iex) functions to APIs that expect `Fn(..) -> Result<..>`. Up to 8
+/// arguments are supported.
+///
+/// The argument types are inferred from the context, so the closure should be passed directly to
+/// a function that constrains its signature.
+///
+/// # Example
+///
+/// ```
+/// use iex::{as_result_fn, iex};
+///
+/// #[iex]
+/// fn checked_divide(a: u32, b: u32) -> Result<u32, &'static str> {
+///     a.checked_div(b).ok_or("Cannot divide by zero")
+/// }
+///
+/// fn divide_all(
+///     pairs: &[(u32, u32)],
+///     divide: impl Fn(u32, u32) -> Result<u32, &'static str>,
+/// ) -> Result<Vec<u32>, &'static str> {
+///     pairs.iter().map(|&(a, b)| divide(a, b)).collect()
+/// }
+///
+/// assert_eq!(divide_all(&[(6, 3), (8, 2)], as_result_fn!(checked_divide, 2)), Ok(vec![2, 4]));
+/// assert!(divide_all(&[(1, 0)], as_result_fn!(checked_divide, 2)).is_err());
+/// ```
+#[macro_export]
+macro_rules! as_result_fn {
+    (@adapt $f:expr; $($arg:ident)*) => {{
+        let f = $f;
+        move |$($arg),*| $crate::Outcome::into_result(f($($arg),*))
+    }};
+    ($f:expr, 0) => { $crate::as_result_fn!(@adapt $f;) };
+    ($f:expr, 1) => { $crate::as_result_fn!(@adapt $f; a1) };
+    ($f:expr, 2) => { $crate::as_result_fn!(@adapt $f; a1 a2) };
+    ($f:expr, 3) => { $crate::as_result_fn!(@adapt $f; a1 a2 a3) };
+    ($f:expr, 4) => { $crate::as_result_fn!(@adapt $f; a1 a2 a3 a4) };
+    ($f:expr, 5) => { $crate::as_result_fn!(@adapt $f; a1 a2 a3 a4 a5) };
+    ($f:expr, 6) => { $crate::as_result_fn!(@adapt $f; a1 a2 a3 a4 a5 a6) };
+    ($f:expr, 7) => { $crate::as_result_fn!(@adapt $f; a1 a2 a3 a4 a5 a6 a7) };
+    ($f:expr, 8) => { $crate::as_result_fn!(@adapt $f; a1 a2 a3 a4 a5 a6 a7 a8) };
+}
+
+/// Assert that an outcome succeeds with a value matching a pattern.
+///
+/// The outcome is resolved via [`into_result`](crate::Outcome::into_result). If the result is an
+/// error, or the value doesn't match the pattern, the macro panics with the pattern and the
+/// [`Debug`] representation of the result. Like with [`assert!`], a custom panic message can be
+/// passed after the pattern.
+///
+/// # Example
+///
+/// ```
+/// use iex::{assert_err_matches, assert_ok_matches, iex};
+///
+/// #[iex]
+/// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// assert_ok_matches!(parse("12"), 10..=20);
+/// assert_ok_matches!(parse("12"), x if x % 2 == 0, "{} should be even", 12);
+/// assert_err_matches!(parse("x"), err if err.to_string().contains("invalid digit"));
+/// ```
+#[macro_export]
+macro_rules! assert_ok_matches {
+    ($outcome:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {
+        match $crate::Outcome::into_result($outcome) {
+            ::core::result::Result::Ok($pattern) $(if $guard)? => {}
+            result => ::core::panic!(
+                "assertion failed: `{}` matches `Ok({})`\n  result: {:?}",
+                ::core::stringify!($outcome),
+                ::core::stringify!($pattern $(if $guard)?),
+                result,
+            ),
+        }
+    };
+    ($outcome:expr, $pattern:pat $(if $guard:expr)?, $($arg:tt)+) => {
+        match $crate::Outcome::into_result($outcome) {
+            ::core::result::Result::Ok($pattern) $(if $guard)? => {}
+            result => ::core::panic!(
+                "assertion failed: `{}` matches `Ok({})`\n  result: {:?}\n  {}",
+                ::core::stringify!($outcome),
+                ::core::stringify!($pattern $(if $guard)?),
+                result,
+                ::core::format_args!($($arg)+),
+            ),
+        }
+    };
+}
+
+/// Assert that an outcome fails with an error matching a pattern.
+///
+/// This is the counterpart of [`assert_ok_matches!`] for the error path. The outcome is resolved
+/// via [`into_result`](crate::Outcome::into_result), and if the result is `Ok`, or the error
+/// doesn't match the pattern, the macro panics with the pattern and the [`Debug`] representation
+/// of the result.
+///
+/// # Example
+///
+/// ```
+/// use iex::{assert_err_matches, iex};
+///
+/// #[derive(Debug)]
+/// enum FetchError {
+///     NotFound,
+///     Timeout(u32),
+/// }
+///
+/// #[iex]
+/// fn fetch(key: &str) -> Result<String, FetchError> {
+///     match key {
+///         "missing" => Err(FetchError::NotFound),
+///         _ => Err(FetchError::Timeout(30)),
+///     }
+/// }
+///
+/// assert_err_matches!(fetch("missing"), FetchError::NotFound);
+/// assert_err_matches!(fetch("slow"), FetchError::Timeout(secs) if secs >= 30);
+/// ```
+#[macro_export]
+macro_rules! assert_err_matches {
+    ($outcome:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {
+        match $crate::Outcome::into_result($outcome) {
+            ::core::result::Result::Err($pattern) $(if $guard)? => {}
+            result => ::core::panic!(
+                "assertion failed: `{}` matches `Err({})`\n  result: {:?}",
+                ::core::stringify!($outcome),
+                ::core::stringify!($pattern $(if $guard)?),
+                result,
+            ),
+        }
+    };
+    ($outcome:expr, $pattern:pat $(if $guard:expr)?, $($arg:tt)+) => {
+        match $crate::Outcome::into_result($outcome) {
+            ::core::result::Result::Err($pattern) $(if $guard)? => {}
+            result => ::core::panic!(
+                "assertion failed: `{}` matches `Err({})`\n  result: {:?}\n  {}",
+                ::core::stringify!($outcome),
+                ::core::stringify!($pattern $(if $guard)?),
+                result,
+                ::core::format_args!($($arg)+),
+            ),
+        }
+    };
+}
@@ -0,0 +1,122 @@
+use crate::{iex, Outcome};
+
+/// Transpose an [`Option`] of an outcome into an outcome of an [`Option`].
+///
+/// `None` is mapped to `Ok(None)`, and `Some(outcome)` is mapped to an outcome that succeeds with
+/// `Some(value)` or fails with the original error.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, transpose_option, Outcome};
+///
+/// #[iex]
+/// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// #[iex]
+/// fn parse_first(strs: &[&str]) -> Result<Option<i32>, std::num::ParseIntError> {
+///     transpose_option(strs.first().map(|s| parse(s)))
+/// }
+///
+/// assert_eq!(parse_first(&["1", "x"]).into_result(), Ok(Some(1)));
+/// assert_eq!(parse_first(&[]).into_result(), Ok(None));
+/// assert!(parse_first(&["x"]).into_result().is_err());
+/// ```
+#[iex]
+pub fn transpose_option<R: Outcome>(option: Option<R>) -> Result<Option<R::Output>, R::Error> {
+    let Some(outcome) = option else {
+        return Ok(None);
+    };
+    Ok(Some(outcome?))
+}
+
+/// Extension methods for raising errors from [`Option`]s.
+pub trait OptionIexExt<T> {
+    /// Convert `Some(value)` to a successful outcome and `None` to an error computed by `err`.
+    ///
+    /// This is similar to [`Option::ok_or_else`], but returns an `#[iex] Result`, so `?` raises
+    /// the error directly instead of going through an intermediate [`Result`]. `err` is only
+    /// called if the option is `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, OptionIexExt, Outcome};
+    /// use std::collections::HashMap;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum ConfigError {
+    ///     Missing(&'static str),
+    /// }
+    ///
+    /// #[iex]
+    /// fn get(config: &HashMap<&str, u32>, key: &'static str) -> Result<u32, ConfigError> {
+    ///     let value = config.get(key).ok_or_iex(|| ConfigError::Missing(key))?;
+    ///     Ok(*value)
+    /// }
+    ///
+    /// let config = HashMap::from([("port", 80)]);
+    /// assert_eq!(get(&config, "port").into_result(), Ok(80));
+    /// assert_eq!(
+    ///     get(&config, "host").into_result(),
+    ///     Err(ConfigError::Missing("host")),
+    /// );
+    /// ```
+    #[iex]
+    fn ok_or_iex<E, F>(self, err: F) -> Result<T, E>
+    where
+        F: FnOnce() -> E;
+
+    /// Transpose an option of an outcome into an outcome of an [`Option`].
+    ///
+    /// This is the method form of [`transpose_option`], and mirrors [`Option::transpose`]. `None`
+    /// is mapped to `Ok(None)`, and the outcome in `Some` is resolved on the same error path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, OptionIexExt, Outcome};
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// #[iex]
+    /// fn parse_port(port: Option<&str>) -> Result<i32, std::num::ParseIntError> {
+    ///     let port = port.map(parse).transpose_iex()?;
+    ///     Ok(port.unwrap_or(80))
+    /// }
+    ///
+    /// assert_eq!(parse_port(Some("8080")).into_result(), Ok(8080));
+    /// assert_eq!(parse_port(None).into_result(), Ok(80));
+    /// assert!(parse_port(Some("x")).into_result().is_err());
+    /// ```
+    #[iex]
+    fn transpose_iex(self) -> Result<Option<T::Output>, T::Error>
+    where
+        T: Outcome;
+}
+
+impl<T> OptionIexExt<T> for Option<T> {
+    #[iex]
+    fn ok_or_iex<E, F>(self, err: F) -> Result<T, E>
+    where
+        F: FnOnce() -> E,
+    {
+        match self {
+            Some(value) => Ok(value),
+            None => Err(err()),
+        }
+    }
+
+    #[iex]
+    fn transpose_iex(self) -> Result<Option<T::Output>, T::Error>
+    where
+        T: Outcome,
+    {
+        transpose_option(self)
+    }
+}
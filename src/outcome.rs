@@ -1,5 +1,16 @@
-use crate::{iex, imp::Marker};
+use crate::{
+    combinator::{AndThen, Combinator, Map, OrElse},
+    iex,
+    imp::{IexResult, Marker},
+};
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "testing")]
+use core::cell::Cell;
+use core::marker::PhantomData;
 
+#[diagnostic::on_unimplemented(message = "`{Self}` is not a `Result` or an `Option`")]
 pub trait Sealed {}
 
 /// Properties of a generalized result type.
@@ -12,7 +23,8 @@ pub trait Sealed {}
 /// and `inspect_err` in some cases. Notably, using `f(...).map_err(|e| ...)` requires that `f(...)`
 /// and `|e| ...` don't capture variables in incompatible ways:
 ///
-/// ```compile_fail
+#[cfg_attr(not(feature = "plain"), doc = " ```compile_fail")]
+#[cfg_attr(feature = "plain", doc = " ```ignore")]
 /// use iex::{iex, Outcome};
 ///
 /// struct Struct;
@@ -82,7 +94,13 @@ pub trait Sealed {}
 /// }
 /// ```
 #[must_use]
-pub trait Outcome: Sealed + crate::Context<Self::Output, Self::Error> {
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a `Result` or an `Option`",
+    note = "`#[iex]` functions must return a `Result`, a type alias of `Result`, or an `Option`"
+)]
+pub trait Outcome:
+    Sealed + crate::Context<Self::Output, Self::Error> + crate::WrapErr<Self::Output, Self::Error>
+{
     /// The type of the success value.
     type Output;
 
@@ -92,6 +110,47 @@ pub trait Outcome: Sealed + crate::Context<Self::Output, Self::Error> {
     #[doc(hidden)]
     fn get_value_or_panic(self, marker: Marker<Self::Error>) -> Self::Output;
 
+    // Called by #[iex] as a method next to `.into_result()?` to check whether `into_result` resolves
+    // to this trait, i.e. whether the trait is in scope. Never actually invoked.
+    #[doc(hidden)]
+    fn _iex_outcome_in_scope(&self) -> crate::imp::_IexOutcomeInScope {
+        crate::imp::_IexOutcomeInScope
+    }
+
+    /// Calls a function with a reference to the contained value if `Ok`.
+    ///
+    /// Returns the original result.
+    ///
+    /// This is a generalized version of [`Result::inspect`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// #[iex]
+    /// fn parse_logged(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     parse(s).inspect(|value| println!("Parsed {value}"))
+    /// }
+    ///
+    /// assert_eq!(parse_logged("12").into_result(), Ok(12));
+    /// ```
+    #[iex]
+    fn inspect<F>(self, f: F) -> Result<Self::Output, Self::Error>
+    where
+        Self: Sized,
+        F: FnOnce(&Self::Output),
+    {
+        let value = self?;
+        f(&value);
+        Ok(value)
+    }
+
     /// Calls a function with a reference to the contained value if `Err`.
     ///
     /// Returns the original result.
@@ -102,9 +161,142 @@ pub trait Outcome: Sealed + crate::Context<Self::Output, Self::Error> {
     where
         F: FnOnce(&Self::Error);
 
+    /// Calls `ok` with a reference to the value if `Ok`, or `err` with a reference to the error if
+    /// `Err`.
+    ///
+    /// Returns the original result.
+    ///
+    /// Exactly one of the two closures is invoked, and it is invoked only after the outcome is
+    /// fully resolved. In particular, if `self` is built from other combinators, such as
+    /// [`map_err`](Self::map_err), `err` observes the error they produce, not an intermediate one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn fails() -> Result<(), i32> {
+    ///     Err(1)
+    /// }
+    ///
+    /// let mut seen = None;
+    /// let result = fails()
+    ///     .map_err(|e| e + 1)
+    ///     .inspect_ok_then_err(|_| unreachable!(), |e| seen = Some(*e))
+    ///     .into_result();
+    /// assert_eq!(result, Err(2));
+    /// assert_eq!(seen, Some(2));
+    /// ```
+    #[iex]
+    fn inspect_ok_then_err<F, G>(self, ok: F, err: G) -> Result<Self::Output, Self::Error>
+    where
+        Self: Sized,
+        F: FnOnce(&Self::Output),
+        G: FnOnce(&Self::Error),
+    {
+        let value = self.inspect_err(err)?;
+        ok(&value);
+        Ok(value)
+    }
+
+    /// Calls a function with a reference to the value if `Ok`, e.g. to populate a cache.
+    ///
+    /// Returns the original result.
+    ///
+    /// `f` is called after the outcome is fully resolved and before the value is handed to the
+    /// caller, so the cache is populated by the time the caller observes the value. The value
+    /// passed to `f` is opaque to the optimizer, so storing it can't be elided even if the value
+    /// is not otherwise used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    /// use std::collections::HashMap;
+    ///
+    /// #[iex]
+    /// fn square(x: u32) -> Result<u32, &'static str> {
+    ///     x.checked_mul(x).ok_or("Overflow")
+    /// }
+    ///
+    /// let mut cache = HashMap::new();
+    /// let result = square(3).inspect_cache(|y| {
+    ///     cache.insert(3, *y);
+    /// });
+    /// assert_eq!(result.into_result(), Ok(9));
+    /// assert_eq!(cache[&3], 9);
+    /// ```
+    #[iex]
+    fn inspect_cache<F>(self, f: F) -> Result<Self::Output, Self::Error>
+    where
+        Self: Sized,
+        F: FnOnce(&Self::Output),
+    {
+        let value = self?;
+        f(core::hint::black_box(&value));
+        Ok(value)
+    }
+
+    /// Calls `cleanup` if `Err`.
+    ///
+    /// Returns the original result.
+    ///
+    /// This is useful for rolling back side effects, e.g. a transaction, only when an error
+    /// propagates. `cleanup` runs while the error is being propagated, before it reaches the caller.
+    #[iex]
+    fn on_error<C>(self, cleanup: C) -> Result<Self::Output, Self::Error>
+    where
+        Self: Sized,
+        C: FnOnce(),
+    {
+        self.inspect_err(|_| cleanup())
+    }
+
+    /// Escalate errors matching `pred` to panics, leaving other errors and `Ok` untouched.
+    ///
+    /// Returns the original result.
+    ///
+    /// This is useful for error kinds that indicate a bug rather than a recoverable condition.
+    /// `pred` is only called if an error occurs. If it returns `true`, the propagation turns into a
+    /// panic, which is resumed by [`into_result`](Self::into_result) and reported by
+    /// [`into_result_preserving_panic`](Self::into_result_preserving_panic) together with the
+    /// error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    /// use std::io::ErrorKind;
+    ///
+    /// #[iex]
+    /// fn read_config(path: &str) -> Result<String, std::io::Error> {
+    ///     std::fs::read_to_string(path)
+    /// }
+    ///
+    /// // A missing file is handled by the caller, but bad permissions are a deployment bug
+    /// let config = read_config("/nonexistent")
+    ///     .panic_on(|err| err.kind() == ErrorKind::PermissionDenied)
+    ///     .into_result();
+    /// assert!(config.is_err());
+    /// ```
+    #[iex]
+    fn panic_on<F>(self, pred: F) -> Result<Self::Output, Self::Error>
+    where
+        Self: Sized,
+        F: FnOnce(&Self::Error) -> bool,
+    {
+        self.inspect_err(|err| {
+            if pred(err) {
+                panic!("error escalated to a panic by `panic_on`");
+            }
+        })
+    }
+
     /// Apply a function to the `Err` value, leaving `Ok` untouched.
     ///
-    /// This is a generalized and more efficient version of [`Result::map_err`].
+    /// This is a generalized and more efficient version of [`Result::map_err`]. Adjacent `map_err`
+    /// calls on an `#[iex] Result` are fused, so a chain of them maps the error just once.
     ///
     /// # Example
     ///
@@ -143,38 +335,1508 @@ pub trait Outcome: Sealed + crate::Context<Self::Output, Self::Error> {
     where
         O: FnOnce(Self::Error) -> F;
 
-    /// Cast a generic result to a [`Result`].
+    /// Apply a function to the `Ok` value, leaving `Err` untouched.
     ///
-    /// The [`Result`] can then be matched on, returned from a function that doesn't use
-    /// [`#[iex]`](macro@crate::iex), etc.
+    /// This is a generalized version of [`Result::map`]. `op` is only called once the outcome is
+    /// resolved, and only if it succeeds. Adjacent `map` calls on an `#[iex] Result` are fused into
+    /// a single closure.
     ///
-    /// This method is typically slow on complex code. Avoid it in the hot path if you can. For
-    /// example,
+    /// # Example
     ///
-    /// ```rust
-    /// # use iex::{iex, Outcome};
-    /// # #[iex] fn f() -> Result<(), ()> { Ok(()) }
-    /// # #[iex] fn g() -> Result<(), ()> { Ok(()) }
-    /// # #[iex] fn fg() -> Result<(), ()> {
-    /// let result = f().into_result();
-    /// g()?;
-    /// result
-    /// # }
     /// ```
+    /// use iex::{iex, Outcome};
     ///
-    /// is perhaps better written as
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
     ///
-    /// ```rust
-    /// # use iex::{iex, Outcome};
-    /// # #[iex] fn f() -> Result<(), ()> { Ok(()) }
-    /// # #[iex] fn g() -> Result<(), ()> { Ok(()) }
-    /// # #[iex] fn fg() -> Result<(), ()> {
-    /// let value = f().inspect_err(|_| drop(g().into_result()))?;
-    /// g()?;
-    /// Ok(value)
-    /// # }
+    /// assert_eq!(parse("21").map(|x| x * 2).into_result(), Ok(42));
+    /// assert!(parse("x").map(|x| x * 2).into_result().is_err());
     /// ```
+    #[cfg(doc)]
+    #[iex]
+    fn map<U, O>(self, op: O) -> Result<U, Self::Error>
+    where
+        Self: Sized,
+        O: FnOnce(Self::Output) -> U,
+    {
+    }
+
+    #[cfg(not(doc))]
+    fn map<U, O>(self, op: O) -> impl Outcome<Output = U, Error = Self::Error>
+    where
+        Self: Sized,
+        O: FnOnce(Self::Output) -> U,
+    {
+        IexResult(Combinator(self, Map(op)), PhantomData)
+    }
+
+    /// Call `op` with the `Ok` value and resolve the outcome it returns, leaving `Err` untouched.
     ///
-    /// despite repetitions.
-    fn into_result(self) -> Result<Self::Output, Self::Error>;
+    /// This is a generalized version of [`Result::and_then`]. `op` may return either a [`Result`]
+    /// or an `#[iex] Result` with the same error type. Note that on a [`Result`], the inherent
+    /// method takes precedence, so use `Outcome::and_then(result, op)` if `op` is an
+    /// [`#[iex]`](macro@crate::iex) function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, &'static str> {
+    ///     s.parse().map_err(|_| "Not a number")
+    /// }
+    ///
+    /// #[iex]
+    /// fn halve(x: u32) -> Result<u32, &'static str> {
+    ///     if x % 2 == 0 {
+    ///         Ok(x / 2)
+    ///     } else {
+    ///         Err("Odd number")
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(parse("42").and_then(halve).into_result(), Ok(21));
+    /// assert_eq!(parse("43").and_then(halve).into_result(), Err("Odd number"));
+    /// assert_eq!(parse("x").and_then(halve).into_result(), Err("Not a number"));
+    /// ```
+    #[cfg(doc)]
+    #[iex]
+    fn and_then<U, O>(self, op: O) -> Result<U::Output, Self::Error>
+    where
+        Self: Sized,
+        U: Outcome<Error = Self::Error>,
+        O: FnOnce(Self::Output) -> U,
+    {
+    }
+
+    #[cfg(not(doc))]
+    fn and_then<U, O>(self, op: O) -> impl Outcome<Output = U::Output, Error = Self::Error>
+    where
+        Self: Sized,
+        U: Outcome<Error = Self::Error>,
+        O: FnOnce(Self::Output) -> U,
+    {
+        IexResult(Combinator(self, AndThen(op)), PhantomData)
+    }
+
+    /// Call a fallible `op` with the `Ok` value, converting its error via [`Into`], and leaving
+    /// `Err` untouched.
+    ///
+    /// Unlike [`and_then`](Self::and_then), `op` returns a [`Result`] whose error type may differ
+    /// from the error type of `self`, as long as it converts to it. This is useful for chaining a
+    /// step that uses a lower-level error type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum ConfigError {
+    ///     Missing,
+    ///     InvalidPort,
+    /// }
+    ///
+    /// impl From<std::num::ParseIntError> for ConfigError {
+    ///     fn from(_: std::num::ParseIntError) -> Self {
+    ///         ConfigError::InvalidPort
+    ///     }
+    /// }
+    ///
+    /// #[iex]
+    /// fn lookup(key: &str) -> Result<&'static str, ConfigError> {
+    ///     match key {
+    ///         "port" => Ok("80"),
+    ///         "host" => Ok("localhost"),
+    ///         _ => Err(ConfigError::Missing),
+    ///     }
+    /// }
+    ///
+    /// let port = |key| lookup(key).and_then_try(str::parse::<u16>).into_result();
+    /// assert_eq!(port("port"), Ok(80));
+    /// assert_eq!(port("host"), Err(ConfigError::InvalidPort));
+    /// assert_eq!(port("user"), Err(ConfigError::Missing));
+    /// ```
+    #[iex]
+    fn and_then_try<U, E2, O>(self, op: O) -> Result<U, Self::Error>
+    where
+        Self: Sized,
+        E2: Into<Self::Error>,
+        O: FnOnce(Self::Output) -> Result<U, E2>,
+    {
+        op(self?).map_err(Into::into)
+    }
+
+    /// Call `op` with the `Err` value and resolve the outcome it returns, leaving `Ok` untouched.
+    ///
+    /// This is a generalized version of [`Result::or_else`]. `op` may return either a [`Result`]
+    /// or an `#[iex] Result` with the same output type, and its error, if any, is propagated. Note
+    /// that on a [`Result`], the inherent method takes precedence, so use
+    /// `Outcome::or_else(result, op)` if `op` is an [`#[iex]`](macro@crate::iex) function.
+    ///
+    /// The happy path is unaffected, but to recover from an error, it has to be caught, just like
+    /// with [`into_result`](Self::into_result).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn read_config(path: &str) -> Result<String, String> {
+    ///     match path {
+    ///         "/etc/app.toml" => Ok("port = 80".to_string()),
+    ///         _ => Err(format!("{path} not found")),
+    ///     }
+    /// }
+    ///
+    /// let config = read_config("~/.app.toml").or_else(|_| read_config("/etc/app.toml"));
+    /// assert_eq!(config.into_result(), Ok("port = 80".to_string()));
+    /// let config = read_config("~/.app.toml").or_else(|_| read_config("./app.toml"));
+    /// assert_eq!(config.into_result(), Err("./app.toml not found".to_string()));
+    /// ```
+    #[cfg(doc)]
+    #[iex]
+    fn or_else<U, O>(self, op: O) -> Result<Self::Output, U::Error>
+    where
+        Self: Sized,
+        U: Outcome<Output = Self::Output>,
+        O: FnOnce(Self::Error) -> U,
+    {
+    }
+
+    #[cfg(not(doc))]
+    fn or_else<U, O>(self, op: O) -> impl Outcome<Output = Self::Output, Error = U::Error>
+    where
+        Self: Sized,
+        U: Outcome<Output = Self::Output>,
+        O: FnOnce(Self::Error) -> U,
+    {
+        IexResult(Combinator(self, OrElse(op)), PhantomData)
+    }
+
+    /// Resolve `self`, discard its value, and then resolve `next`.
+    ///
+    /// This sequences two fallible steps whose values don't depend on each other, e.g. side
+    /// effects. If `self` fails, its error is returned and `next` is not resolved.
+    ///
+    /// Unlike [`and_then`](Self::and_then), `next` is an outcome rather than a closure, so it is
+    /// constructed before `self` is resolved. For an [`#[iex]`](macro@crate::iex) function, this
+    /// only evaluates the arguments, and its body still runs after `self` succeeds. A plain
+    /// [`Result`], however, is already computed by then, so use [`and_then`](Self::and_then) if the
+    /// second step has to be skipped entirely on failure.
+    ///
+    /// # Example
+    ///
+    // The leading space matches `///`, so that the docs appended by #[iex] are unindented as usual
+    #[cfg_attr(not(feature = "plain"), doc = " ```")]
+    #[cfg_attr(feature = "plain", doc = " ```ignore")]
+    /// use iex::{iex, Outcome};
+    /// use std::cell::RefCell;
+    ///
+    /// #[iex]
+    /// fn step(log: &RefCell<Vec<u32>>, id: u32) -> Result<u32, String> {
+    ///     if id == 0 {
+    ///         return Err("Invalid step".to_string());
+    ///     }
+    ///     log.borrow_mut().push(id);
+    ///     Ok(id)
+    /// }
+    ///
+    /// let log = RefCell::new(Vec::new());
+    /// assert_eq!(step(&log, 1).then(step(&log, 2)).into_result(), Ok(2));
+    /// assert!(step(&log, 0).then(step(&log, 3)).into_result().is_err());
+    /// assert_eq!(*log.borrow(), [1, 2]);
+    /// ```
+    #[iex]
+    fn then<O>(self, next: O) -> Result<O::Output, Self::Error>
+    where
+        Self: Sized,
+        O: Outcome<Error = Self::Error>,
+    {
+        self?;
+        next
+    }
+
+    /// Resolve `self`, and then resolve the outcome it produced.
+    ///
+    /// This collapses an outcome of an outcome, e.g. produced by [`map`](Self::map) with an
+    /// [`#[iex]`](macro@crate::iex) function, into a single outcome. An error from either level is
+    /// propagated, and the inner outcome is resolved on the same error path, without being caught
+    /// and rethrown. Note that on a [`Result`], the inherent method takes precedence, so use
+    /// `Outcome::flatten(result)` if the `Ok` value is an `#[iex] Result`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, &'static str> {
+    ///     s.parse().map_err(|_| "Not a number")
+    /// }
+    ///
+    /// #[iex]
+    /// fn validate(x: u32) -> Result<u32, &'static str> {
+    ///     if x < 100 {
+    ///         Ok(x)
+    ///     } else {
+    ///         Err("Too large")
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(parse("42").map(validate).flatten().into_result(), Ok(42));
+    /// assert_eq!(parse("420").map(validate).flatten().into_result(), Err("Too large"));
+    /// assert_eq!(parse("x").map(validate).flatten().into_result(), Err("Not a number"));
+    /// ```
+    #[iex]
+    fn flatten(self) -> Result<<Self::Output as Outcome>::Output, Self::Error>
+    where
+        Self: Sized,
+        Self::Output: Outcome<Error = Self::Error>,
+    {
+        self?
+    }
+
+    /// Apply a function to the `Err` value and the backtrace of the point where it was raised,
+    /// leaving `Ok` untouched.
+    ///
+    /// This lets error types embed a backtrace only when they are constructed. The backtrace is
+    /// captured with [`Backtrace::capture`](std::backtrace::Backtrace::capture), so it is only
+    /// populated if enabled by the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables.
+    ///
+    /// The backtrace of the raise point can only be retrieved once. If it was already consumed by
+    /// another `map_err_backtrace` call, or if `self` is a [`Result`], the backtrace is captured
+    /// when `map` is called instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    /// use std::backtrace::Backtrace;
+    ///
+    /// struct MyError {
+    ///     message: String,
+    ///     backtrace: Backtrace,
+    /// }
+    ///
+    /// #[iex]
+    /// fn fails() -> Result<(), String> {
+    ///     Err("Failed".to_string())
+    /// }
+    ///
+    /// #[iex]
+    /// fn calls() -> Result<(), MyError> {
+    ///     fails().map_err_backtrace(|message, backtrace| MyError { message, backtrace })
+    /// }
+    /// ```
+    #[cfg(feature = "backtrace")]
+    #[iex]
+    fn map_err_backtrace<F, M>(self, map: M) -> Result<Self::Output, F>
+    where
+        Self: Sized,
+        M: FnOnce(Self::Error, std::backtrace::Backtrace) -> F,
+    {
+        self.map_err(|err| {
+            let backtrace = crate::take_backtrace();
+            map(
+                err,
+                backtrace.unwrap_or_else(std::backtrace::Backtrace::capture),
+            )
+        })
+    }
+
+    /// Hold a guard while the outcome is resolved and its value is used.
+    ///
+    /// If the outcome is `Ok`, `use_guard` is called with the value and a reference to `guard`, and
+    /// `guard` is dropped right after `use_guard` returns. If the outcome is `Err`, `guard` is
+    /// dropped while the error is being propagated. This is useful for holding a borrow or a file
+    /// handle while a value is being produced and consumed.
+    ///
+    /// Errors are propagated by unwinding, so [`std::thread::panicking`] returns `true` while the
+    /// guard is dropped on the error path. In particular, a [`MutexGuard`](std::sync::MutexGuard)
+    /// poisons its mutex in this case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    /// use std::cell::RefCell;
+    ///
+    /// #[iex]
+    /// fn parse_index(s: &str) -> Result<usize, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// let names = RefCell::new(vec!["Alice", "Bob"]);
+    /// let name = parse_index("1")
+    ///     .with_guard(names.borrow(), |index, names| names.get(index).copied())
+    ///     .into_result();
+    /// assert_eq!(name, Ok(Some("Bob")));
+    /// // The borrow is released on both paths
+    /// assert!(names.try_borrow_mut().is_ok());
+    /// assert!(parse_index("x")
+    ///     .with_guard(names.borrow(), |_, _| unreachable!())
+    ///     .into_result()
+    ///     .is_err());
+    /// assert!(names.try_borrow_mut().is_ok());
+    /// ```
+    #[iex]
+    fn with_guard<G, R, F>(self, guard: G, use_guard: F) -> Result<R, Self::Error>
+    where
+        Self: Sized,
+        F: FnOnce(Self::Output, &G) -> R,
+    {
+        let value = self?;
+        let result = use_guard(value, &guard);
+        drop(guard);
+        Ok(result)
+    }
+
+    /// Carry `value` through to both the `Ok` and the `Err` value.
+    ///
+    /// On success, the output is paired with `value`, and on failure, the error is paired with
+    /// `value`. This is useful when the caller needs an associated value, e.g. a request ID or a
+    /// buffer to reuse, regardless of the result. The error is propagated on the error path as
+    /// usual, without being caught.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn handle(request: &str) -> Result<usize, String> {
+    ///     if request.is_empty() {
+    ///         Err("Empty request".to_string())
+    ///     } else {
+    ///         Ok(request.len())
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(handle("GET /").attach(1).into_result(), Ok((5, 1)));
+    /// assert_eq!(
+    ///     handle("").attach(2).into_result(),
+    ///     Err(("Empty request".to_string(), 2)),
+    /// );
+    /// ```
+    #[iex]
+    fn attach<V>(self, value: V) -> Result<(Self::Output, V), (Self::Error, V)>
+    where
+        Self: Sized,
+    {
+        let mut value = Some(value);
+        let output = self.map_err(|err| (err, value.take().unwrap()))?;
+        Ok((output, value.unwrap()))
+    }
+
+    /// Count how often the outcome succeeds and fails.
+    ///
+    /// When the outcome is resolved, the first counter is incremented if it succeeds, and the
+    /// second counter is incremented if it fails. The error path is detected by a guard that is
+    /// disarmed on success, so the counters stay correct while the error is propagated by
+    /// unwinding. Panics unrelated to error propagation are counted as failures too.
+    ///
+    /// This is meant for tests of performance-sensitive code, e.g. to check that the error path is
+    /// taken as rarely as expected in a loop, and is only available with the `testing` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    /// use std::cell::Cell;
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// let counters = Cell::new((0, 0));
+    /// for s in ["1", "x", "2"] {
+    ///     let _ = parse(s).count_paths(&counters).into_result();
+    /// }
+    /// assert_eq!(counters.get(), (2, 1));
+    /// ```
+    #[cfg(feature = "testing")]
+    #[iex]
+    fn count_paths(self, counters: &Cell<(u64, u64)>) -> Result<Self::Output, Self::Error>
+    where
+        Self: Sized,
+    {
+        struct ErrorPathGuard<'a>(&'a Cell<(u64, u64)>);
+
+        impl Drop for ErrorPathGuard<'_> {
+            fn drop(&mut self) {
+                let (ok, err) = self.0.get();
+                self.0.set((ok, err + 1));
+            }
+        }
+
+        let guard = ErrorPathGuard(counters);
+        let value = self?;
+        core::mem::forget(guard);
+        let (ok, err) = counters.get();
+        counters.set((ok + 1, err));
+        Ok(value)
+    }
+
+    /// Enter a [`tracing::Span`] for the duration of the resolution.
+    ///
+    /// The span is entered before the computation starts and exited once it finishes, on both the
+    /// happy and the error path, so events emitted during the computation are recorded under
+    /// `span`. The span is exited by a guard, so entries and exits stay balanced while the error is
+    /// propagated by unwinding.
+    ///
+    /// Only the lazy part of the computation is covered. For example, if `self` is a [`Result`], it
+    /// has already been computed by the time `in_span` is called.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     tracing::debug!("parsing {s}");
+    ///     s.parse()
+    /// }
+    ///
+    /// #[iex]
+    /// fn parse_port(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     // The event in `parse` is recorded under the `port` span
+    ///     parse(s).in_span(tracing::info_span!("port"))
+    /// }
+    /// ```
+    #[cfg(feature = "tracing")]
+    #[iex]
+    fn in_span(self, span: tracing::Span) -> Result<Self::Output, Self::Error>
+    where
+        Self: Sized,
+    {
+        let _entered = span.enter();
+        Ok(self?)
+    }
+
+    /// Convert the `Err` value to an [`eyre::Report`], leaving `Ok` untouched.
+    ///
+    /// The conversion only happens on the error path. Use [`WrapErr`](crate::WrapErr) to add
+    /// context to the report.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome, WrapErr};
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// #[iex]
+    /// fn parse_port(s: &str) -> Result<i32, eyre::Report> {
+    ///     parse(s).into_eyre().wrap_err("Invalid port")
+    /// }
+    ///
+    /// let report = parse_port("x").into_result().unwrap_err();
+    /// assert_eq!(report.to_string(), "Invalid port");
+    /// ```
+    #[cfg(feature = "eyre")]
+    #[iex]
+    fn into_eyre(self) -> Result<Self::Output, eyre::Report>
+    where
+        Self: Sized,
+        Self::Error: core::error::Error + Send + Sync + 'static,
+    {
+        self.map_err(eyre::Report::new)
+    }
+
+    /// Start a breadcrumb trail on the `Err` value, leaving `Ok` untouched.
+    ///
+    /// The error is paired with a list of static strings describing where it propagated through,
+    /// which is a lightweight alternative to context chains. Use
+    /// [`push_breadcrumb`](Self::push_breadcrumb) to extend the trail in outer layers. The trail is
+    /// only built on the error path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// #[iex]
+    /// fn parse_port(s: &str) -> Result<u32, (std::num::ParseIntError, Vec<&'static str>)> {
+    ///     parse(s).breadcrumb("parsing port")
+    /// }
+    ///
+    /// #[iex]
+    /// fn load_config(s: &str) -> Result<u32, (std::num::ParseIntError, Vec<&'static str>)> {
+    ///     parse_port(s).push_breadcrumb("loading config")
+    /// }
+    ///
+    /// let (_, trail) = load_config("x").into_result().unwrap_err();
+    /// assert_eq!(trail, ["parsing port", "loading config"]);
+    /// ```
+    #[iex]
+    fn breadcrumb(
+        self,
+        crumb: &'static str,
+    ) -> Result<Self::Output, (Self::Error, Vec<&'static str>)>
+    where
+        Self: Sized,
+    {
+        self.map_err(move |err| (err, alloc::vec![crumb]))
+    }
+
+    /// Append a breadcrumb to the trail carried by the `Err` value, leaving `Ok` untouched.
+    ///
+    /// See [`breadcrumb`](Self::breadcrumb) for more information.
+    #[iex]
+    fn push_breadcrumb<E>(self, crumb: &'static str) -> Result<Self::Output, (E, Vec<&'static str>)>
+    where
+        Self: Sized + Outcome<Error = (E, Vec<&'static str>)>,
+    {
+        self.map_err(move |(err, mut crumbs)| {
+            crumbs.push(crumb);
+            (err, crumbs)
+        })
+    }
+
+    /// Convert the `Err` value to a [`String`], leaving `Ok` untouched.
+    ///
+    /// This is a shorthand for `.map_err(|err| err.to_string())`.
+    #[iex]
+    fn map_err_to_string(self) -> Result<Self::Output, String>
+    where
+        Self: Sized,
+        Self::Error: core::fmt::Display,
+    {
+        self.map_err(|err| err.to_string())
+    }
+
+    /// Convert the `Err` value to `E` via [`Into`], leaving `Ok` untouched.
+    ///
+    /// This is useful for collapsing errors that were wrapped one layer too many, e.g. by a
+    /// conversion to an enum variant that is then converted back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct ParseError;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct AppError(&'static str);
+    ///
+    /// impl From<ParseError> for AppError {
+    ///     fn from(_: ParseError) -> Self {
+    ///         AppError("parse error")
+    ///     }
+    /// }
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, ParseError> {
+    ///     s.parse().map_err(|_| ParseError)
+    /// }
+    ///
+    /// assert_eq!(
+    ///     parse("x").flatten_err::<AppError>().into_result(),
+    ///     Err(AppError("parse error")),
+    /// );
+    /// ```
+    #[iex]
+    fn flatten_err<E>(self) -> Result<Self::Output, E>
+    where
+        Self: Sized,
+        Self::Error: Into<E>,
+    {
+        self.map_err(Into::into)
+    }
+
+    /// Merge a [`Result`]-typed `Err` value into a single error type, leaving `Ok` untouched.
+    ///
+    /// An error of type `Result<E1, E2>` is sometimes produced by layered error handling, e.g. by
+    /// [`or_else`](Self::or_else) recovery whose own failure is reported as `Ok`. Both `Ok(e1)` and
+    /// `Err(e2)` are converted to `E` via [`Into`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn fetch(cached: bool) -> Result<u32, Result<&'static str, String>> {
+    ///     if cached {
+    ///         Err(Ok("stale cache"))
+    ///     } else {
+    ///         Err(Err("network down".to_string()))
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     fetch(true).flatten_err_result::<String, _, _>().into_result(),
+    ///     Err("stale cache".to_string()),
+    /// );
+    /// assert_eq!(
+    ///     fetch(false).flatten_err_result::<String, _, _>().into_result(),
+    ///     Err("network down".to_string()),
+    /// );
+    /// ```
+    #[iex]
+    fn flatten_err_result<E, E1, E2>(self) -> Result<Self::Output, E>
+    where
+        Self: Sized + Outcome<Error = Result<E1, E2>>,
+        E1: Into<E>,
+        E2: Into<E>,
+    {
+        self.map_err(|err| match err {
+            Ok(err) => err.into(),
+            Err(err) => err.into(),
+        })
+    }
+
+    /// Wrap the `Err` value in a [`WrapError`](crate::WrapError) with a description of what was
+    /// being done, leaving `Ok` untouched.
+    ///
+    /// The wrapped error is accessible via [`source`](std::error::Error::source), so wrapping
+    /// errors at several levels produces an error chain without external crates. `context` is
+    /// only converted to a [`String`] if an error occurs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome, WrapError};
+    /// use std::error::Error;
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u16, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// #[iex]
+    /// fn load_config(port: &str) -> Result<u16, WrapError<std::num::ParseIntError>> {
+    ///     parse(port).map_err_wrap(format!("Invalid port {port:?}"))
+    /// }
+    ///
+    /// let err = load_config("x").into_result().unwrap_err();
+    /// assert_eq!(err.to_string(), "Invalid port \"x\"");
+    /// assert!(err.source().is_some());
+    /// ```
+    #[iex]
+    fn map_err_wrap<C>(self, context: C) -> Result<Self::Output, crate::WrapError<Self::Error>>
+    where
+        Self: Sized,
+        C: Into<String>,
+    {
+        self.map_err(move |error| crate::WrapError {
+            context: context.into(),
+            error,
+        })
+    }
+
+    /// Wrap the `Err` value in a [`ContextError`](crate::ContextError) with the given context,
+    /// leaving `Ok` untouched.
+    ///
+    /// This is similar to `anyhow`'s `context`, but keeps the concrete error type. The context is
+    /// boxed only if an error occurs; nothing is done on the happy path.
+    ///
+    /// The method is not called `context` because, with the `anyhow` feature, `iex::Context`
+    /// provides `context` and `with_context` converting the error to `anyhow::Error`. As that trait
+    /// is a supertrait of `Outcome`, a second method with the same name would make calls
+    /// ambiguous, e.g. in all code generic over `Outcome`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, ContextError, Outcome};
+    ///
+    /// #[iex]
+    /// fn read_config(path: &str) -> Result<String, std::io::Error> {
+    ///     std::fs::read_to_string(path)
+    /// }
+    ///
+    /// #[iex]
+    /// fn start_server() -> Result<(), ContextError<std::io::Error>> {
+    ///     let config = read_config("/nonexistent/server.toml")
+    ///         .map_err_context("loading server config")?;
+    ///     println!("{config}");
+    ///     Ok(())
+    /// }
+    ///
+    /// let err = start_server().into_result().unwrap_err();
+    /// assert_eq!(err.to_string(), "loading server config");
+    /// ```
+    #[iex]
+    fn map_err_context<C>(
+        self,
+        context: C,
+    ) -> Result<Self::Output, crate::ContextError<Self::Error>>
+    where
+        Self: Sized,
+        C: core::fmt::Display + Send + Sync + 'static,
+    {
+        self.map_err(move |source| crate::ContextError {
+            context: Box::new(context),
+            source,
+        })
+    }
+
+    /// Wrap the `Err` value in a [`ContextError`](crate::ContextError) with the context returned by
+    /// `f`, leaving `Ok` untouched.
+    ///
+    /// `f` is only called if an error occurs. This is the lazy variant of
+    /// [`map_err_context`](Outcome::map_err_context), named so for the same reason.
+    #[iex]
+    fn map_err_with_context<C, F>(
+        self,
+        f: F,
+    ) -> Result<Self::Output, crate::ContextError<Self::Error>>
+    where
+        Self: Sized,
+        C: core::fmt::Display + Send + Sync + 'static,
+        F: FnOnce() -> C,
+    {
+        self.map_err(move |source| crate::ContextError {
+            context: Box::new(f()),
+            source,
+        })
+    }
+
+    /// Erase the `Err` value to a boxed [`Display`](std::fmt::Display) trait object, leaving `Ok`
+    /// untouched.
+    ///
+    /// This is useful when the error only needs to be shown, not inspected or downcast. The error
+    /// is boxed only if it occurs.
+    #[iex]
+    fn erase_display(self) -> Result<Self::Output, Box<dyn core::fmt::Display + Send>>
+    where
+        Self: Sized,
+        Self::Error: core::fmt::Display + Send + 'static,
+    {
+        self.map_err(|err| Box::new(err) as Box<dyn core::fmt::Display + Send>)
+    }
+
+    /// Resolve the outcome and map the success value to an iterator of outcomes.
+    ///
+    /// `self` is resolved lazily, when [`next`](Iterator::next) is called for the first time. If
+    /// it fails, the returned iterator yields a single outcome that fails with the same error.
+    /// Otherwise, the iterator yields the items produced by `f`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn count() -> Result<u32, String> {
+    ///     Ok(3)
+    /// }
+    ///
+    /// #[iex]
+    /// fn check(x: u32) -> Result<u32, String> {
+    ///     if x == 1 { Err(format!("{x} is odd")) } else { Ok(x) }
+    /// }
+    ///
+    /// let results: Vec<_> = count()
+    ///     .flat_map_iter(|n| (0..n).map(check))
+    ///     .map(Outcome::into_result)
+    ///     .collect();
+    /// assert_eq!(results, [Ok(0), Err("1 is odd".to_string()), Ok(2)]);
+    /// ```
+    fn flat_map_iter<U, I, F>(
+        self,
+        f: F,
+    ) -> impl Iterator<Item = impl Outcome<Output = U, Error = Self::Error>>
+    where
+        Self: Sized,
+        I: IntoIterator,
+        I::Item: Outcome<Output = U, Error = Self::Error>,
+        F: FnOnce(Self::Output) -> I,
+    {
+        crate::iter::FlatMapIter::new(self, f)
+    }
+
+    /// Returns the contained `Ok` value, or aborts the process if the outcome is `Err`.
+    ///
+    /// The error is printed to stderr before aborting. Unlike [`Result::unwrap`], this never
+    /// unwinds on error, which makes it suitable for boundaries where unwinding is not an option,
+    /// e.g. FFI callbacks, or for errors indicating corrupted invariants. Note that panics
+    /// unrelated to error propagation still unwind.
+    #[cfg(feature = "std")]
+    fn unwrap_or_abort(self) -> Self::Output
+    where
+        Self: Sized,
+        Self::Error: core::fmt::Debug,
+    {
+        self.into_result().unwrap_or_else(|err| {
+            std::eprintln!("called `Outcome::unwrap_or_abort()` on an `Err` value: {err:?}");
+            std::process::abort()
+        })
+    }
+
+    /// Returns the contained `Ok` value, or panics with `msg` and the error.
+    ///
+    /// This is the counterpart of [`Result::expect`]: the panic message is `msg` followed by the
+    /// [`Debug`](core::fmt::Debug) representation of the error. The panic is a regular one, so it
+    /// can't be mistaken for error propagation, and panics unrelated to error propagation are
+    /// resumed unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```should_panic
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// assert_eq!(parse("1").expect("Invalid number"), 1);
+    /// // Panics with "Invalid number: ParseIntError { kind: InvalidDigit }"
+    /// parse("x").expect("Invalid number");
+    /// ```
+    #[track_caller]
+    fn expect(self, msg: &str) -> Self::Output
+    where
+        Self: Sized,
+        Self::Error: core::fmt::Debug,
+    {
+        match self.into_result() {
+            Ok(value) => value,
+            Err(err) => panic!("{msg}: {err:?}"),
+        }
+    }
+
+    /// Returns the contained `Ok` value, or panics with a message computed from the error.
+    ///
+    /// Unlike [`Result::expect`], this doesn't require the error to implement
+    /// [`Debug`](std::fmt::Debug). `f` is only called if the outcome is `Err`, so the message is not
+    /// formatted on the happy path.
+    ///
+    /// # Example
+    ///
+    /// ```should_panic
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn read_config(path: &str) -> Result<String, std::io::Error> {
+    ///     std::fs::read_to_string(path)
+    /// }
+    ///
+    /// let config = read_config("/nonexistent")
+    ///     .expect_with(|err| format!("Failed to read config: {err}"));
+    /// ```
+    #[track_caller]
+    fn expect_with<F>(self, f: F) -> Self::Output
+    where
+        Self: Sized,
+        F: FnOnce(&Self::Error) -> String,
+    {
+        match self.into_result() {
+            Ok(value) => value,
+            Err(err) => panic!("{}", f(&err)),
+        }
+    }
+
+    /// Box the `Ok` value, leaving `Err` untouched.
+    ///
+    /// This is useful when the caller needs indirection, e.g. to store a large value or to convert
+    /// it to an unsized type like `Box<str>` or `Box<dyn Trait>`. Nothing is allocated if an error
+    /// occurs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn make_table(len: usize) -> Result<[u64; 1024], &'static str> {
+    ///     if len > 1024 {
+    ///         return Err("Too long");
+    ///     }
+    ///     Ok(std::array::from_fn(|i| if i < len { i as u64 } else { 0 }))
+    /// }
+    ///
+    /// let table: Box<[u64]> = make_table(3).boxed_ok().into_result().unwrap();
+    /// assert_eq!(table[..4], [0, 1, 2, 0]);
+    /// ```
+    #[iex]
+    fn boxed_ok(self) -> Result<Box<Self::Output>, Self::Error>
+    where
+        Self: Sized,
+    {
+        self.map(Box::new)
+    }
+
+    /// Box the outcome into a [`DynOutcome`](crate::DynOutcome).
+    ///
+    /// This is useful for storing outcomes, or closures returning them, in struct fields and
+    /// collections.
+    fn boxed<'a>(self) -> crate::DynOutcome<'a, Self::Output, Self::Error>
+    where
+        Self: Sized + 'a,
+    {
+        crate::imp::IexResult(
+            Box::new(move |marker| self.get_value_or_panic(marker)),
+            core::marker::PhantomData,
+        )
+    }
+
+    /// Cast a generic result to a [`Result`].
+    ///
+    /// The [`Result`] can then be matched on, returned from a function that doesn't use
+    /// [`#[iex]`](macro@crate::iex), etc.
+    ///
+    /// This method is typically slow on complex code. Avoid it in the hot path if you can. For
+    /// example,
+    ///
+    /// ```rust
+    /// # use iex::{iex, Outcome};
+    /// # #[iex] fn f() -> Result<(), ()> { Ok(()) }
+    /// # #[iex] fn g() -> Result<(), ()> { Ok(()) }
+    /// # #[iex] fn fg() -> Result<(), ()> {
+    /// let result = f().into_result();
+    /// g()?;
+    /// result
+    /// # }
+    /// ```
+    ///
+    /// is perhaps better written as
+    ///
+    /// ```rust
+    /// # use iex::{iex, Outcome};
+    /// # #[iex] fn f() -> Result<(), ()> { Ok(()) }
+    /// # #[iex] fn g() -> Result<(), ()> { Ok(()) }
+    /// # #[iex] fn fg() -> Result<(), ()> {
+    /// let value = f().inspect_err(|_| drop(g().into_result()))?;
+    /// g()?;
+    /// Ok(value)
+    /// # }
+    /// ```
+    ///
+    /// despite repetitions.
+    ///
+    /// Calling `into_result` on an [`#[iex]`](macro@crate::iex) function whose body doesn't use
+    /// `?`, doesn't invoke macros, and only ever evaluates to `Ok(..)` is free, because such a
+    /// function cannot fail.
+    ///
+    /// `into_result` can be nested. In particular, it may be called by error handlers and by
+    /// destructors of local variables while another error is being propagated, and the errors
+    /// won't interfere with each other.
+    fn into_result(self) -> Result<Self::Output, Self::Error>;
+
+    /// Cast a generic result to a [`Result`], reporting panics in error handlers.
+    ///
+    /// If an error handler, e.g. a closure passed to [`map_err`](Self::map_err), panics while an
+    /// error is being propagated, [`into_result`](Self::into_result) resumes that panic. This
+    /// method returns it as a [`ResolveError`](crate::ResolveError) instead, which is useful for
+    /// graceful degradation at library boundaries. Panics that are not related to error
+    /// propagation are resumed as usual.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(not(feature = "plain"), doc = " ```")]
+    #[cfg_attr(feature = "plain", doc = " ```ignore")]
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn fails() -> Result<(), i32> {
+    ///     Err(1)
+    /// }
+    ///
+    /// let result = fails()
+    ///     .map_err(|_| -> i32 { panic!("Error handler failed") })
+    ///     .into_result_preserving_panic();
+    /// let err = result.unwrap_err();
+    /// assert!(err.original.is_none());
+    /// assert_eq!(err.panic.downcast_ref::<&str>(), Some(&"Error handler failed"));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn into_result_preserving_panic(
+        self,
+    ) -> Result<Result<Self::Output, Self::Error>, crate::ResolveError<Self::Error>>
+    where
+        Self: Sized,
+    {
+        Ok(self.into_result())
+    }
+
+    /// Cast a generic result to a [`Result`], keeping the backtrace of the point where the error
+    /// was raised.
+    ///
+    /// Unlike [`last_backtrace`](crate::last_backtrace), this ties the backtrace to the error it
+    /// belongs to, so it's not affected by other errors caught on the same thread.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(not(feature = "plain"), doc = " ```")]
+    #[cfg_attr(feature = "plain", doc = " ```ignore")]
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn fails() -> Result<(), &'static str> {
+    ///     Err("Failed")
+    /// }
+    ///
+    /// let caught = fails().into_result_with_backtrace().unwrap_err();
+    /// assert_eq!(*caught.error(), "Failed");
+    /// println!("Raised at:\n{}", caught.backtrace().unwrap());
+    /// ```
+    #[cfg(feature = "backtrace")]
+    fn into_result_with_backtrace(self) -> Result<Self::Output, crate::Caught<Self::Error>>
+    where
+        Self: Sized,
+    {
+        self.into_result()
+            .map_err(|error| crate::Caught::new(error, None))
+    }
+
+    /// Cast a generic result to a [`Result`] and classify the error, if any.
+    ///
+    /// Returns the result and, if it is `Err`, the value returned by `classify`. This is useful for
+    /// retry logic, where the error is inspected to decide whether to retry, and propagated
+    /// otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn flaky(attempt: u32) -> Result<u32, String> {
+    ///     if attempt < 2 { Err("timeout".to_string()) } else { Ok(attempt) }
+    /// }
+    ///
+    /// let mut attempt = 0;
+    /// let result = loop {
+    ///     match flaky(attempt).resolve_classified(|err| err == "timeout") {
+    ///         (_, Some(true)) => attempt += 1,
+    ///         (result, _) => break result,
+    ///     }
+    /// };
+    /// assert_eq!(result, Ok(2));
+    /// ```
+    fn resolve_classified<C>(self, classify: C) -> (Result<Self::Output, Self::Error>, Option<bool>)
+    where
+        Self: Sized,
+        C: FnOnce(&Self::Error) -> bool,
+    {
+        let result = self.into_result();
+        let class = result.as_ref().err().map(classify);
+        (result, class)
+    }
+
+    /// Cast a generic result to a [`Result`], running callbacks around the resolution.
+    ///
+    /// `before` is called right before the outcome is resolved. As an `#[iex] Result` is lazy, this
+    /// means before the body of the [`#[iex]`](macro@crate::iex) function runs. `after` is called
+    /// with a reference to the result, and its return value is returned alongside the result. This
+    /// is useful for instrumentation, e.g. timing or tracing resolution boundaries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    /// use std::cell::Cell;
+    /// use std::time::Instant;
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// let start = Cell::new(None);
+    /// let (result, elapsed) = parse("12").resolve_with_hook(
+    ///     || start.set(Some(Instant::now())),
+    ///     |_| start.get().unwrap().elapsed(),
+    /// );
+    /// assert_eq!(result, Ok(12));
+    /// println!("Resolved in {elapsed:?}");
+    /// ```
+    fn resolve_with_hook<R, B, A>(
+        self,
+        before: B,
+        after: A,
+    ) -> (Result<Self::Output, Self::Error>, R)
+    where
+        Self: Sized,
+        B: FnOnce(),
+        A: FnOnce(&Result<Self::Output, Self::Error>) -> R,
+    {
+        before();
+        let result = self.into_result();
+        let hooked = after(&result);
+        (result, hooked)
+    }
+
+    /// Resolve the outcome, mapping the `Ok` value with `on_ok` or the `Err` value with `on_err`.
+    ///
+    /// This is a generalized version of [`Result::map_or_else`]. Exactly one of the closures is
+    /// called, and it takes ownership of the value or the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// let parse_or_default = |s| {
+    ///     parse(s).resolve_to(
+    ///         |value| value,
+    ///         |err| {
+    ///             std::eprintln!("Failed to parse {s:?}: {err}");
+    ///             0
+    ///         },
+    ///     )
+    /// };
+    /// assert_eq!(parse_or_default("12"), 12);
+    /// assert_eq!(parse_or_default("x"), 0);
+    /// ```
+    fn resolve_to<U, F, G>(self, on_ok: F, on_err: G) -> U
+    where
+        Self: Sized,
+        F: FnOnce(Self::Output) -> U,
+        G: FnOnce(Self::Error) -> U,
+    {
+        match self.into_result() {
+            Ok(value) => on_ok(value),
+            Err(err) => on_err(err),
+        }
+    }
+
+    /// Resolve the outcome, applying `f` to the value or returning `default` on error.
+    ///
+    /// This is a generalized version of [`Result::map_or`]. `f` is only called if the outcome
+    /// succeeds. Like with the standard method, `default` is evaluated eagerly; use
+    /// [`map_or_else`](Self::map_or_else) if computing it is expensive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// assert_eq!(parse("21").map_or(0, |n| n * 2), 42);
+    /// assert_eq!(parse("x").map_or(0, |n| n * 2), 0);
+    /// ```
+    fn map_or<U, F>(self, default: U, f: F) -> U
+    where
+        Self: Sized,
+        F: FnOnce(Self::Output) -> U,
+    {
+        match self.into_result() {
+            Ok(value) => f(value),
+            Err(_) => default,
+        }
+    }
+
+    /// Resolve the outcome, applying `f` to the value or `default` to the error.
+    ///
+    /// This is a generalized version of [`Result::map_or_else`]. Exactly one of the closures is
+    /// called. This is the same as [`resolve_to`](Self::resolve_to) with the arguments swapped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// let describe = |s| parse(s).map_or_else(|err| err.to_string(), |n| format!("got {n}"));
+    /// assert_eq!(describe("12"), "got 12");
+    /// assert_eq!(describe("x"), "invalid digit found in string");
+    /// ```
+    fn map_or_else<U, D, F>(self, default: D, f: F) -> U
+    where
+        Self: Sized,
+        D: FnOnce(Self::Error) -> U,
+        F: FnOnce(Self::Output) -> U,
+    {
+        self.resolve_to(f, default)
+    }
+
+    /// Resolve the outcome, returning the value or `default` on error.
+    ///
+    /// This is a generalized version of [`Result::unwrap_or`]. Like with the standard method,
+    /// `default` is evaluated eagerly, even if the outcome succeeds. Use
+    /// [`unwrap_or_else`](Self::unwrap_or_else) if computing it is expensive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn parse_port(s: &str) -> Result<u16, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// assert_eq!(parse_port("80").unwrap_or(8080), 80);
+    /// assert_eq!(parse_port("x").unwrap_or(8080), 8080);
+    /// ```
+    fn unwrap_or(self, default: Self::Output) -> Self::Output
+    where
+        Self: Sized,
+    {
+        self.into_result().unwrap_or(default)
+    }
+
+    /// Resolve the outcome, returning the value or computing it from the error.
+    ///
+    /// This is a generalized version of [`Result::unwrap_or_else`]. `f` is only called if the
+    /// outcome fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn parse_port(s: &str) -> Result<u16, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// let port = parse_port("x").unwrap_or_else(|err| {
+    ///     eprintln!("Invalid port: {err}");
+    ///     8080
+    /// });
+    /// assert_eq!(port, 8080);
+    /// ```
+    fn unwrap_or_else<F>(self, f: F) -> Self::Output
+    where
+        Self: Sized,
+        F: FnOnce(Self::Error) -> Self::Output,
+    {
+        self.into_result().unwrap_or_else(f)
+    }
+
+    /// Resolve the outcome on a worker thread, returning `default` if it fails or takes too long.
+    ///
+    /// This is meant for best-effort code, e.g. optional lookups that shouldn't delay the caller.
+    /// The outcome is moved to a new thread and resolved there, so it has to be `Send` and
+    /// `'static`. If the result doesn't arrive within `timeout`, `default` is returned and the
+    /// worker is left running in the background; its result is discarded. Panics on the worker are
+    /// reported by the panic hook and also make this method return `default`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    /// use std::time::Duration;
+    ///
+    /// #[iex]
+    /// fn lookup(host: &'static str) -> Result<u16, String> {
+    ///     if host == "slow" {
+    ///         std::thread::sleep(Duration::from_secs(1));
+    ///     }
+    ///     host.parse().map_err(|_| format!("Unknown host {host}"))
+    /// }
+    ///
+    /// let timeout = Duration::from_millis(100);
+    /// assert_eq!(lookup("80").resolve_or_default_within(timeout, 0), 80);
+    /// assert_eq!(lookup("x").resolve_or_default_within(timeout, 0), 0);
+    /// assert_eq!(lookup("slow").resolve_or_default_within(timeout, 0), 0);
+    /// ```
+    #[cfg(feature = "std")]
+    fn resolve_or_default_within(
+        self,
+        timeout: core::time::Duration,
+        default: Self::Output,
+    ) -> Self::Output
+    where
+        Self: Sized + Send + 'static,
+        Self::Output: Send + 'static,
+    {
+        let (tx, rx) = std::sync::mpsc::sync_channel(1);
+        std::thread::spawn(move || {
+            // The receiver is gone if the deadline has passed
+            let _ = tx.send(self.ok());
+        });
+        rx.recv_timeout(timeout).ok().flatten().unwrap_or(default)
+    }
+
+    /// Resolve the outcome, returning the value if it succeeds.
+    ///
+    /// This is a generalized version of [`Result::ok`]. The error, if any, is discarded. Like
+    /// [`into_result`](Self::into_result), this consumes the outcome and has the same cost.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// let numbers: Vec<u32> = ["1", "x", "3"].into_iter().filter_map(|s| parse(s).ok()).collect();
+    /// assert_eq!(numbers, [1, 3]);
+    /// ```
+    fn ok(self) -> Option<Self::Output>
+    where
+        Self: Sized,
+    {
+        self.into_result().ok()
+    }
+
+    /// Resolve the outcome, returning the error if it fails.
+    ///
+    /// This is a generalized version of [`Result::err`]. The outcome is driven to completion even
+    /// if it succeeds, so side effects happen just like with [`into_result`](Self::into_result),
+    /// and the value is then dropped. The cost is the same as that of `into_result`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// assert!(parse("12").err().is_none());
+    /// assert_eq!(parse("x").err().unwrap().to_string(), "invalid digit found in string");
+    /// ```
+    fn err(self) -> Option<Self::Error>
+    where
+        Self: Sized,
+    {
+        self.into_result().err()
+    }
+
+    /// Resolve the outcome into a pair of options, exactly one of which is `Some`.
+    ///
+    /// Returns `(Some(value), None)` on success and `(None, Some(error))` on failure. This is
+    /// occasionally more convenient than a [`Result`], e.g. when the value and the error are sent
+    /// to different places.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// let (values, errors): (Vec<_>, Vec<_>) = ["1", "x", "3"]
+    ///     .into_iter()
+    ///     .map(|s| parse(s).resolve_split())
+    ///     .unzip();
+    /// assert_eq!(values, [Some(1), None, Some(3)]);
+    /// assert_eq!(errors.iter().flatten().count(), 1);
+    /// ```
+    fn resolve_split(self) -> (Option<Self::Output>, Option<Self::Error>)
+    where
+        Self: Sized,
+    {
+        match self.into_result() {
+            Ok(value) => (Some(value), None),
+            Err(err) => (None, Some(err)),
+        }
+    }
+
+    /// Resolve the outcome into an arbitrary [`Try`](std::ops::Try) type.
+    ///
+    /// This generalizes [`into_result`](Self::into_result) to any `Try` type whose residual
+    /// implements [`ErrorResidual`](crate::ErrorResidual), e.g. a [`Result`] with an error type that
+    /// implements `From<Self::Error>`, a [`ControlFlow`](std::ops::ControlFlow) that breaks with
+    /// the error, or a custom result-like type.
+    ///
+    /// The target is selected by its residual rather than by
+    /// `FromResidual<Result<Infallible, Self::Error>>`, because `ControlFlow` only accepts
+    /// `ControlFlow` residuals.
+    ///
+    /// Requires the `nightly` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #![feature(try_trait_v2)]
+    /// use iex::{iex, Outcome};
+    /// use std::ops::ControlFlow;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct AppError(String);
+    ///
+    /// impl From<std::num::ParseIntError> for AppError {
+    ///     fn from(err: std::num::ParseIntError) -> Self {
+    ///         AppError(err.to_string())
+    ///     }
+    /// }
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// let result: Result<u32, AppError> = parse("x").catch_into();
+    /// assert_eq!(result, Err(AppError("invalid digit found in string".to_string())));
+    ///
+    /// let flow: ControlFlow<AppError, u32> = parse("1").catch_into();
+    /// assert_eq!(flow, ControlFlow::Continue(1));
+    /// ```
+    #[cfg(feature = "nightly")]
+    fn catch_into<R>(self) -> R
+    where
+        Self: Sized,
+        R: core::ops::Try<Output = Self::Output>,
+        R::Residual: crate::ErrorResidual<Self::Error>,
+    {
+        match self.into_result() {
+            Ok(value) => R::from_output(value),
+            Err(err) => R::from_residual(crate::ErrorResidual::from_error(err)),
+        }
+    }
+
+    /// Resolve the outcome, converting the error to a [`String`].
+    ///
+    /// This is the terminal counterpart of [`map_err_to_string`](Self::map_err_to_string), useful
+    /// for reporting errors at the top level. The error is only formatted if it occurs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iex::{iex, Outcome};
+    ///
+    /// #[iex]
+    /// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// assert_eq!(parse("12").resolve_err_string(), Ok(12));
+    /// assert_eq!(
+    ///     parse("x").resolve_err_string(),
+    ///     Err("invalid digit found in string".to_string()),
+    /// );
+    /// ```
+    fn resolve_err_string(self) -> Result<Self::Output, String>
+    where
+        Self: Sized,
+        Self::Error: core::fmt::Display,
+    {
+        self.into_result().map_err(|err| err.to_string())
+    }
 }
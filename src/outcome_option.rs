@@ -0,0 +1,127 @@
+use crate::{imp::Marker, outcome::Sealed, Outcome};
+
+/// Properties of a generalized option type.
+///
+/// This unifies [`Option`] and `#[iex] Option`, which is returned by
+/// [`#[iex]`](macro@crate::iex) functions declared to return an [`Option`]. In such functions, `?`
+/// propagates `None` by unwinding, just like errors are propagated in functions returning
+/// [`Result`], so the happy path doesn't branch on the discriminant.
+///
+/// `?` can only be applied to [`Option`] and `#[iex] Option` in these functions. To propagate a
+/// `None` from a function returning [`Result`], convert it to an error first, e.g. with
+/// [`ok_or_iex`](crate::OptionIexExt::ok_or_iex).
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, OutcomeOption};
+/// use std::collections::HashMap;
+///
+/// struct Config {
+///     aliases: HashMap<String, String>,
+///     ports: HashMap<String, u16>,
+/// }
+///
+/// impl Config {
+///     #[iex]
+///     fn resolve(&self, name: &str) -> Option<&str> {
+///         Some(self.aliases.get(name)?.as_str())
+///     }
+///
+///     #[iex]
+///     fn port(&self, name: &str) -> Option<u16> {
+///         let name = self.resolve(name)?;
+///         Some(*self.ports.get(name)?)
+///     }
+/// }
+///
+/// let config = Config {
+///     aliases: HashMap::from([("web".to_string(), "http".to_string())]),
+///     ports: HashMap::from([("http".to_string(), 80)]),
+/// };
+/// assert_eq!(config.port("web").into_option(), Some(80));
+/// assert_eq!(config.port("db").into_option(), None);
+/// ```
+///
+/// Mixing [`Result`] and [`Option`] is an error, just like with the built-in `?`:
+///
+/// ```compile_fail
+/// use iex::iex;
+///
+/// #[iex]
+/// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// #[iex]
+/// fn parse_or_none(s: &str) -> Option<u32> {
+///     // `impl Outcome<..>` is not an `Option` or `#[iex] Option`
+///     Some(parse(s)?)
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use iex::iex;
+///
+/// #[iex]
+/// fn unwrap_or_err(x: Option<u32>) -> Result<u32, String> {
+///     // the trait `Outcome` is not implemented for `Option<u32>`
+///     Ok(x?)
+/// }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an `Option` or `#[iex] Option`",
+    note = "`?` in `#[iex]` functions returning `Option` can only be applied to options"
+)]
+pub trait OutcomeOption: Sealed {
+    /// The type of the success value.
+    type Output;
+
+    #[doc(hidden)]
+    fn get_value_or_panic(self, marker: Marker<NoneError>) -> Self::Output;
+
+    /// Cast a generic option to a [`Option`].
+    ///
+    /// This is the counterpart of [`Outcome::into_result`] for `#[iex] Option`.
+    fn into_option(self) -> Option<Self::Output>;
+}
+
+// The error an #[iex] Option propagates in place of `None`
+#[doc(hidden)]
+pub struct NoneError;
+
+impl<T> Sealed for Option<T> {}
+
+impl<T> OutcomeOption for Option<T> {
+    type Output = T;
+
+    fn get_value_or_panic(self, marker: Marker<NoneError>) -> T {
+        self.ok_or(NoneError).get_value_or_panic(marker)
+    }
+
+    fn into_option(self) -> Self {
+        self
+    }
+}
+
+#[doc(hidden)]
+pub struct IexOption<R>(pub R);
+
+impl<R> Sealed for IexOption<R> {}
+
+impl<R: Outcome<Error = NoneError>> OutcomeOption for IexOption<R> {
+    type Output = R::Output;
+
+    fn get_value_or_panic(self, marker: Marker<NoneError>) -> R::Output {
+        self.0.get_value_or_panic(marker)
+    }
+
+    fn into_option(self) -> Option<R::Output> {
+        self.0.into_result().ok()
+    }
+}
+
+#[doc(hidden)]
+pub fn _iex_forward_option<R: OutcomeOption>(marker: Marker<NoneError>, option: R) -> R::Output {
+    option.get_value_or_panic(marker)
+}
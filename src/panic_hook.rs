@@ -0,0 +1,74 @@
+use crate::{Host, Payload, Platform};
+#[cfg(feature = "std")]
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use core::any::Any;
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+
+#[cfg(feature = "std")]
+type ForeignPanicHook = Box<dyn Fn(&(dyn Any + Send)) + Send + Sync + 'static>;
+
+#[cfg(feature = "std")]
+static FOREIGN_PANIC_HOOK: RwLock<Option<ForeignPanicHook>> = RwLock::new(None);
+
+/// Register a hook that observes panics unrelated to error propagation.
+///
+/// [`into_result`](crate::Outcome::into_result) catches unwinding to resolve errors. Panics that
+/// don't originate from raising an error, i.e. bugs, are re-raised as-is. Before that happens,
+/// `hook` is called with the panic payload, which is useful for telling errors and bugs apart in
+/// metrics.
+///
+/// The hook is invoked every time the panic passes through `into_result`, so a panic crossing
+/// several nested `into_result` calls is observed several times. If the hook panics, that panic is
+/// ignored, and the original panic keeps unwinding.
+///
+/// Outcomes of [`#[iex]`](macro@crate::iex) functions that provably never raise errors are resolved
+/// without catching panics, so the hook is not invoked for panics passing through their
+/// `into_result`. Such panics are still observed by any other `into_result` they pass through.
+///
+/// Only one hook can be registered at a time. Registering a new hook replaces the previous one.
+///
+/// # Example
+///
+#[cfg_attr(not(feature = "plain"), doc = " ```")]
+#[cfg_attr(feature = "plain", doc = " ```ignore")]
+/// use iex::{iex, Outcome};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// static BUGS: AtomicUsize = AtomicUsize::new(0);
+///
+/// iex::set_foreign_panic_hook(Box::new(|_payload| {
+///     BUGS.fetch_add(1, Ordering::Relaxed);
+/// }));
+///
+/// #[iex]
+/// fn parse_first(values: &[&str]) -> Result<u32, std::num::ParseIntError> {
+///     // Panics if `values` is empty
+///     values[0].parse()
+/// }
+///
+/// assert!(parse_first(&["x"]).into_result().is_err());
+/// assert_eq!(BUGS.load(Ordering::Relaxed), 0);
+/// assert!(std::panic::catch_unwind(|| parse_first(&[]).into_result()).is_err());
+/// assert_eq!(BUGS.load(Ordering::Relaxed), 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn set_foreign_panic_hook(hook: ForeignPanicHook) {
+    *FOREIGN_PANIC_HOOK
+        .write()
+        .unwrap_or_else(|err| err.into_inner()) = Some(hook);
+}
+
+#[cold]
+pub(crate) fn resume_foreign_panic(payload: Payload) -> ! {
+    #[cfg(feature = "std")]
+    if let Some(hook) = &*FOREIGN_PANIC_HOOK
+        .read()
+        .unwrap_or_else(|err| err.into_inner())
+    {
+        // Don't let the hook replace the panic we're propagating
+        let _ = Host::catch_unwind(|| hook(&*payload));
+    }
+    Host::resume_unwind(payload)
+}
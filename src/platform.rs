@@ -0,0 +1,161 @@
+use crate::exception::Exception;
+use alloc::boxed::Box;
+use core::any::Any;
+use core::cell::UnsafeCell;
+
+pub type Payload = Box<dyn Any + Send>;
+
+// The primitives error propagation is built upon: a slot for the error being propagated, a slot for
+// a panic that occurred in an error handler, and unwinding. With `std`, the slots are thread-local
+// and unwinding is performed by `std::panic`. Without `std`, the slots are statics and unwinding is
+// delegated to the shim registered with `unwind_shim!`.
+pub(crate) trait Platform {
+    fn with_exception<R>(f: impl FnOnce(&UnsafeCell<Exception>) -> R) -> R;
+
+    fn catch_unwind<R>(f: impl FnOnce() -> R) -> Result<R, Payload>;
+
+    fn resume_unwind(payload: Payload) -> !;
+}
+
+#[cfg(feature = "std")]
+pub(crate) type Host = Std;
+
+#[cfg(not(feature = "std"))]
+pub(crate) type Host = Shim;
+
+#[cfg(feature = "std")]
+pub(crate) struct Std;
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static EXCEPTION: UnsafeCell<Exception> = const { UnsafeCell::new(Exception::new()) };
+}
+
+#[cfg(feature = "std")]
+impl Platform for Std {
+    #[inline(always)]
+    fn with_exception<R>(f: impl FnOnce(&UnsafeCell<Exception>) -> R) -> R {
+        EXCEPTION.with(f)
+    }
+
+    #[inline(always)]
+    fn catch_unwind<R>(f: impl FnOnce() -> R) -> Result<R, Payload> {
+        std::panic::catch_unwind(core::panic::AssertUnwindSafe(f))
+    }
+
+    fn resume_unwind(payload: Payload) -> ! {
+        std::panic::resume_unwind(payload)
+    }
+}
+
+/// Unwinding primitives for targets without `std`.
+///
+/// When the `std` feature is disabled, iex cannot rely on `std::panic` to raise and catch errors,
+/// so the platform has to provide these two primitives itself, typically by wrapping the
+/// `_Unwind_*` API of its unwinder. The implementation is registered with [`unwind_shim!`]. Exactly
+/// one shim must be registered in the final binary, otherwise linking fails.
+///
+/// The target must support unwinding and the binary must be built with `panic = "unwind"`: errors
+/// are still propagated by unwinding. Since there is no thread-local storage in `core`, the error
+/// being propagated is stored in a static, so iex must only ever be used from a single thread.
+///
+/// # Safety
+///
+/// [`catch_unwind`](Self::catch_unwind) must call `f` exactly once. If `f` returns, it must return
+/// `Ok(())`. If `f` unwinds via [`resume_unwind`](Self::resume_unwind), it must stop the unwinding
+/// and return `Err` with the payload passed to `resume_unwind`, as is.
+///
+/// Implementing this trait also asserts that the program never uses iex from more than one thread
+/// of execution, including interrupt handlers that might preempt a thread using iex. The state of
+/// the error being propagated is stored in statics that are not synchronized.
+///
+/// # Example
+///
+/// ```ignore
+/// struct Unwinder;
+///
+/// unsafe impl iex::UnwindShim for Unwinder {
+///     fn catch_unwind(f: &mut dyn FnMut()) -> Result<(), Box<dyn Any + Send>> {
+///         my_unwinder::catch(f)
+///     }
+///
+///     fn resume_unwind(payload: Box<dyn Any + Send>) -> ! {
+///         my_unwinder::throw(payload)
+///     }
+/// }
+///
+/// iex::unwind_shim!(Unwinder);
+/// ```
+#[cfg(not(feature = "std"))]
+pub unsafe trait UnwindShim {
+    /// Call `f`, catching the unwinding started by [`resume_unwind`](Self::resume_unwind).
+    fn catch_unwind(f: &mut dyn FnMut()) -> Result<(), Box<dyn Any + Send>>;
+
+    /// Start unwinding with the given payload.
+    fn resume_unwind(payload: Box<dyn Any + Send>) -> !;
+}
+
+/// Register the [`UnwindShim`] iex uses when the `std` feature is disabled.
+///
+/// Must be invoked exactly once in the dependency graph, usually by the binary crate.
+#[cfg(not(feature = "std"))]
+#[macro_export]
+macro_rules! unwind_shim {
+    ($shim:ty) => {
+        #[no_mangle]
+        fn __iex_catch_unwind(
+            f: &mut dyn FnMut(),
+        ) -> ::core::result::Result<(), $crate::imp::Payload> {
+            <$shim as $crate::UnwindShim>::catch_unwind(f)
+        }
+
+        #[no_mangle]
+        fn __iex_resume_unwind(payload: $crate::imp::Payload) -> ! {
+            <$shim as $crate::UnwindShim>::resume_unwind(payload)
+        }
+    };
+}
+
+#[cfg(not(feature = "std"))]
+extern "Rust" {
+    fn __iex_catch_unwind(f: &mut dyn FnMut()) -> Result<(), Payload>;
+    fn __iex_resume_unwind(payload: Payload) -> !;
+}
+
+// Sound because registering an UnwindShim asserts that iex is only used from a single thread
+#[cfg(not(feature = "std"))]
+struct SingleThreaded<T>(T);
+
+#[cfg(not(feature = "std"))]
+unsafe impl<T> Sync for SingleThreaded<T> {}
+
+#[cfg(not(feature = "std"))]
+static EXCEPTION: SingleThreaded<UnsafeCell<Exception>> =
+    SingleThreaded(UnsafeCell::new(Exception::new()));
+
+#[cfg(not(feature = "std"))]
+pub(crate) struct Shim;
+
+#[cfg(not(feature = "std"))]
+impl Platform for Shim {
+    #[inline(always)]
+    fn with_exception<R>(f: impl FnOnce(&UnsafeCell<Exception>) -> R) -> R {
+        f(&EXCEPTION.0)
+    }
+
+    fn catch_unwind<R>(f: impl FnOnce() -> R) -> Result<R, Payload> {
+        let mut f = Some(f);
+        let mut value = None;
+        unsafe {
+            __iex_catch_unwind(&mut || {
+                value = f.take().map(|f| f());
+            })?;
+        }
+        // The shim is trusted to call the closure exactly once
+        Ok(unsafe { value.unwrap_unchecked() })
+    }
+
+    fn resume_unwind(payload: Payload) -> ! {
+        unsafe { __iex_resume_unwind(payload) }
+    }
+}
@@ -0,0 +1,113 @@
+use std::alloc::{GlobalAlloc, Layout};
+#[cfg(debug_assertions)]
+use std::cell::Cell;
+
+#[cfg(debug_assertions)]
+std::thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    // Allocations performed by iex while propagating the current error
+    static ERROR_PATH_ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[cfg(debug_assertions)]
+fn allocations() -> usize {
+    ALLOCATIONS.try_with(Cell::get).unwrap_or(0)
+}
+
+/// A global allocator wrapper that lets iex detect allocations on the error path.
+///
+/// Real-time code often chooses `#[iex]` for its deterministic happy path, but an error type that
+/// allocates, e.g. when it is too large to be stored inline or when
+/// [`map_err`](crate::Outcome::map_err) formats a message, silently breaks that determinism on the
+/// error path.
+///
+/// When this allocator is installed and debug assertions are enabled,
+/// [`into_result`](crate::Outcome::into_result) panics if iex allocated memory while propagating
+/// the error it resolves. Allocations made before the error is raised, e.g. while constructing it,
+/// are not tracked. In release builds, this is a zero-cost wrapper around `A`.
+///
+/// # Example
+///
+/// ```
+/// use iex::CountingAllocator;
+/// use std::alloc::System;
+///
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator<System> = CountingAllocator(System);
+/// ```
+pub struct CountingAllocator<A>(pub A);
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count_allocation();
+        self.0.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count_allocation();
+        self.0.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count_allocation();
+        self.0.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.0.dealloc(ptr, layout)
+    }
+}
+
+#[inline(always)]
+fn count_allocation() {
+    #[cfg(debug_assertions)]
+    let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+}
+
+// Attributes allocations performed during its lifetime to the error path
+#[cfg(debug_assertions)]
+pub(crate) struct ErrorPathGuard {
+    saved: usize,
+    allocations_at_start: usize,
+}
+
+#[cfg(debug_assertions)]
+impl ErrorPathGuard {
+    pub(crate) fn enter() -> Self {
+        Self {
+            // A nested into_result() call only checks allocations of its own error path
+            saved: ERROR_PATH_ALLOCATIONS.take(),
+            allocations_at_start: allocations(),
+        }
+    }
+}
+
+#[cfg(debug_assertions)]
+impl Drop for ErrorPathGuard {
+    fn drop(&mut self) {
+        // allocations() reads as zero once the thread-local is torn down
+        let allocated = allocations().saturating_sub(self.allocations_at_start);
+        let _ = ERROR_PATH_ALLOCATIONS.try_with(|error_path_allocations| {
+            error_path_allocations.set(self.saved + error_path_allocations.get() + allocated);
+        });
+    }
+}
+
+#[cfg(debug_assertions)]
+pub(crate) fn take_error_path_allocations() -> usize {
+    ERROR_PATH_ALLOCATIONS.take()
+}
+
+#[cfg(debug_assertions)]
+pub(crate) fn set_error_path_allocations(allocated: usize) {
+    ERROR_PATH_ALLOCATIONS.set(allocated);
+}
+
+#[cfg(debug_assertions)]
+pub(crate) fn check_error_path() {
+    let allocated = ERROR_PATH_ALLOCATIONS.take();
+    assert!(
+        allocated == 0,
+        "{allocated} allocation(s) were performed on the error path",
+    );
+}
@@ -0,0 +1,22 @@
+use alloc::boxed::Box;
+use core::any::Any;
+
+/// A panic that occurred while an error was being propagated.
+///
+/// Error handlers, such as closures passed to [`map_err`](crate::Outcome::map_err) and
+/// [`inspect_err`](crate::Outcome::inspect_err), are invoked while the error is being propagated
+/// by unwinding. If such a handler panics, [`into_result`](crate::Outcome::into_result) resumes
+/// the panic, and [`into_result_preserving_panic`](crate::Outcome::into_result_preserving_panic)
+/// returns it as a `ResolveError`.
+#[derive(Debug)]
+pub struct ResolveError<E> {
+    /// The propagated error, if it survived the panic.
+    ///
+    /// This is [`Some`] if the panic occurred in [`inspect_err`](crate::Outcome::inspect_err), and
+    /// [`None`] if the error was consumed by the panicking handler, e.g. in
+    /// [`map_err`](crate::Outcome::map_err).
+    pub original: Option<E>,
+
+    /// The panic payload.
+    pub panic: Box<dyn Any + Send>,
+}
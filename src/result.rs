@@ -1,4 +1,10 @@
-use crate::{imp::Marker, outcome::Sealed, IexPanic, Outcome, EXCEPTION};
+use crate::{
+    imp::{IexResult, Marker},
+    outcome::Sealed,
+    Host, IexPanic, Outcome, Platform,
+};
+use alloc::boxed::Box;
+use core::marker::PhantomData;
 
 impl<T, E> Sealed for Result<T, E> {}
 
@@ -9,9 +15,24 @@ impl<T, E> Outcome for Result<T, E> {
 
     fn get_value_or_panic(self, _marker: Marker<E>) -> T {
         self.unwrap_or_else(|error| {
-            EXCEPTION.with(|exception| unsafe { &mut *exception.get() }.write(error));
+            {
+                #[cfg(all(feature = "realtime", debug_assertions))]
+                let _guard = crate::realtime::ErrorPathGuard::enter();
+                // Capturing a backtrace allocates, so it's part of the error path
+                #[cfg(feature = "backtrace")]
+                let backtrace = std::backtrace::Backtrace::capture();
+                Host::with_exception(|exception| {
+                    let exception = unsafe { &mut *exception.get() };
+                    // Anything left over was raised by an error caught outside of iex, e.g. by
+                    // catch_unwind, and doesn't belong to this propagation
+                    exception.clear();
+                    exception.write(error);
+                    #[cfg(feature = "backtrace")]
+                    exception.set_backtrace(backtrace);
+                });
+            }
             // This does not allocate, because IexPanic is a ZST.
-            std::panic::resume_unwind(Box::new(IexPanic))
+            Host::resume_unwind(Box::new(IexPanic))
         })
     }
 
@@ -23,12 +44,14 @@ impl<T, E> Outcome for Result<T, E> {
     {
     }
 
-    #[cfg(not(doc))]
+    #[cfg(all(not(doc), not(feature = "plain")))]
     fn inspect_err<F>(self, f: F) -> impl Outcome<Output = T, Error = E>
     where
         F: FnOnce(&Self::Error),
     {
-        Result::inspect_err(self, f)
+        // Call `f` while the error is being propagated, like `#[iex] Result` does, so that a panic
+        // in `f` is reported the same way
+        IexResult(move |marker| self.get_value_or_panic(marker), PhantomData).inspect_err(f)
     }
 
     #[cfg(doc)]
@@ -39,7 +62,7 @@ impl<T, E> Outcome for Result<T, E> {
     {
     }
 
-    #[cfg(not(doc))]
+    #[cfg(all(not(doc), not(feature = "plain")))]
     fn map_err<F, O>(self, op: O) -> impl Outcome<Output = Self::Output, Error = F>
     where
         O: FnOnce(E) -> F,
@@ -47,6 +70,51 @@ impl<T, E> Outcome for Result<T, E> {
         Result::map_err(self, op)
     }
 
+    // With the `plain` feature, these methods are declared to return plain Results
+    #[cfg(all(not(doc), feature = "plain"))]
+    fn inspect_err<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&Self::Error),
+    {
+        Result::inspect_err(self, f)
+    }
+
+    #[cfg(all(not(doc), feature = "plain"))]
+    fn map_err<F, O>(self, op: O) -> Result<T, F>
+    where
+        O: FnOnce(E) -> F,
+    {
+        Result::map_err(self, op)
+    }
+
+    // With the `plain` feature, combinators on Results resolve eagerly, so that errors are never
+    // propagated by unwinding
+    #[cfg(all(not(doc), feature = "plain"))]
+    fn map<U, O>(self, op: O) -> impl Outcome<Output = U, Error = E>
+    where
+        O: FnOnce(T) -> U,
+    {
+        Result::map(self, op)
+    }
+
+    #[cfg(all(not(doc), feature = "plain"))]
+    fn and_then<U, O>(self, op: O) -> impl Outcome<Output = U::Output, Error = E>
+    where
+        U: Outcome<Error = E>,
+        O: FnOnce(T) -> U,
+    {
+        Result::and_then(self, |value| op(value).into_result())
+    }
+
+    #[cfg(all(not(doc), feature = "plain"))]
+    fn or_else<U, O>(self, op: O) -> impl Outcome<Output = T, Error = U::Error>
+    where
+        U: Outcome<Output = T>,
+        O: FnOnce(E) -> U,
+    {
+        Result::or_else(self, |err| op(err).into_result())
+    }
+
     fn into_result(self) -> Self {
         self
     }
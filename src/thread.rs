@@ -0,0 +1,129 @@
+use crate::Outcome;
+#[cfg(feature = "rayon")]
+use alloc::vec::Vec;
+use std::sync::mpsc::{SendError, Sender};
+use std::thread::{Scope, ScopedJoinHandle};
+
+/// Run a fallible computation on a scoped thread.
+///
+/// `f` is invoked on a new thread spawned in `scope`, and the outcome it returns is resolved on
+/// that thread. Joining the returned handle yields the [`Result`].
+///
+/// As with [`Scope::spawn`], `f` can borrow non-`'static` data from outside the scope.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, spawn_scoped_resolve};
+///
+/// #[iex]
+/// fn sum(numbers: &[u32]) -> Result<u32, &'static str> {
+///     numbers
+///         .iter()
+///         .try_fold(0u32, |acc, &x| acc.checked_add(x))
+///         .ok_or("Overflow")
+/// }
+///
+/// let numbers = vec![1, 2, 3, u32::MAX];
+/// std::thread::scope(|s| {
+///     let head = spawn_scoped_resolve(s, || sum(&numbers[..3]));
+///     let all = spawn_scoped_resolve(s, || sum(&numbers));
+///     assert_eq!(head.join().unwrap(), Ok(6));
+///     assert_eq!(all.join().unwrap(), Err("Overflow"));
+/// });
+/// ```
+pub fn spawn_scoped_resolve<'scope, 'env, F, R>(
+    scope: &'scope Scope<'scope, 'env>,
+    f: F,
+) -> ScopedJoinHandle<'scope, Result<R::Output, R::Error>>
+where
+    F: FnOnce() -> R + Send + 'scope,
+    R: Outcome,
+    R::Output: Send + 'scope,
+    R::Error: Send + 'scope,
+{
+    scope.spawn(move || f().into_result())
+}
+
+/// Resolve outcomes and send the results to a channel.
+///
+/// Unlike [`try_collect_indexed`](crate::try_collect_indexed), this doesn't stop at the first
+/// error: every outcome is resolved in order, and its [`Result`] is sent to `tx`, so that a
+/// consumer thread can process the results as they arrive. Sending stops early only if the
+/// receiver is disconnected, in which case the unsent result is returned.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, send_results};
+/// use std::sync::mpsc;
+///
+/// #[iex]
+/// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// let (tx, rx) = mpsc::channel();
+/// let producer = std::thread::spawn(move || send_results(["1", "x", "3"].map(parse), &tx));
+/// let results: Vec<_> = rx.iter().map(|result| result.is_ok()).collect();
+/// assert_eq!(results, [true, false, true]);
+/// producer.join().unwrap().unwrap();
+/// ```
+pub fn send_results<T, E, I>(
+    outcomes: I,
+    tx: &Sender<Result<T, E>>,
+) -> Result<(), SendError<Result<T, E>>>
+where
+    I: IntoIterator,
+    I::Item: Outcome<Output = T, Error = E>,
+{
+    let mut outcomes = outcomes.into_iter();
+    for outcome in outcomes.by_ref() {
+        if let Err(err) = tx.send(outcome.into_result()) {
+            crate::iex_result::discard(outcomes);
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
+/// Apply a fallible function to items in parallel and collect the values.
+///
+/// Errors are propagated via thread-local storage, so an outcome has to be resolved on the same
+/// thread it's produced on. With [`rayon`](https://docs.rs/rayon), this means that
+/// [`into_result`](Outcome::into_result) must be called inside the closure running on the worker
+/// thread, not after the parallel computation has finished. This function does exactly that: `f`
+/// is called on a worker, and the outcome it returns is resolved right there.
+///
+/// If any outcome fails, the parallel iterator is short-circuited: items not yet processed are
+/// skipped, and one of the errors is returned. Which error is returned is unspecified if several
+/// items fail.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, par_map_collect};
+///
+/// #[iex]
+/// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// assert_eq!(par_map_collect(vec!["1", "2", "3"], parse), Ok(vec![1, 2, 3]));
+/// assert!(par_map_collect(vec!["1", "x", "3"], parse).is_err());
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_map_collect<I, F, R>(items: I, f: F) -> Result<Vec<R::Output>, R::Error>
+where
+    I: rayon::iter::IntoParallelIterator,
+    F: Fn(I::Item) -> R + Sync + Send,
+    R: Outcome,
+    R::Output: Send,
+    R::Error: Send,
+{
+    use rayon::iter::ParallelIterator;
+    items
+        .into_par_iter()
+        .map(|item| f(item).into_result())
+        .collect()
+}
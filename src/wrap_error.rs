@@ -0,0 +1,98 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::error::Error;
+use core::fmt;
+
+/// An error with a human-readable description of what was being done when it occurred.
+///
+/// Created by [`map_err_wrap`](crate::Outcome::map_err_wrap). Displays as the context, and returns
+/// the wrapped error from [`source`](Error::source), so that wrapping errors repeatedly produces
+/// an error chain.
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, Outcome, WrapError};
+/// use std::error::Error;
+///
+/// #[iex]
+/// fn parse_port(s: &str) -> Result<u16, WrapError<std::num::ParseIntError>> {
+///     s.parse().map_err_wrap("Failed to parse port")
+/// }
+///
+/// let err = parse_port("x").into_result().unwrap_err();
+/// assert_eq!(err.to_string(), "Failed to parse port");
+/// assert_eq!(err.source().unwrap().to_string(), "invalid digit found in string");
+/// ```
+#[derive(Debug)]
+pub struct WrapError<E> {
+    /// The description of what was being done.
+    pub context: String,
+
+    /// The wrapped error.
+    pub error: E,
+}
+
+impl<E> fmt::Display for WrapError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.context)
+    }
+}
+
+impl<E: Error + 'static> Error for WrapError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// An error with a lazily formatted message describing what was being done when it occurred.
+///
+/// Created by [`map_err_context`](crate::Outcome::map_err_context) and
+/// [`map_err_with_context`](crate::Outcome::map_err_with_context). Unlike [`WrapError`], the
+/// context is not converted to a string, so any [`Display`](fmt::Display) type can be attached
+/// without formatting it up front. Displays as the context, and returns the wrapped error from
+/// [`source`](Error::source).
+///
+/// # Example
+///
+/// ```
+/// use iex::{iex, ContextError, Outcome};
+/// use std::error::Error;
+///
+/// #[iex]
+/// fn parse_port(s: &str) -> Result<u16, ContextError<std::num::ParseIntError>> {
+///     s.parse().map_err_context("loading server config")
+/// }
+///
+/// let err = parse_port("x").into_result().unwrap_err();
+/// assert_eq!(err.to_string(), "loading server config");
+/// assert_eq!(err.source().unwrap().to_string(), "invalid digit found in string");
+/// ```
+pub struct ContextError<E> {
+    /// The description of what was being done.
+    pub context: Box<dyn fmt::Display + Send + Sync>,
+
+    /// The wrapped error.
+    pub source: E,
+}
+
+impl<E: fmt::Debug> fmt::Debug for ContextError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContextError")
+            .field("context", &format_args!("{}", self.context))
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+impl<E> fmt::Display for ContextError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.context.fmt(f)
+    }
+}
+
+impl<E: Error + 'static> Error for ContextError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+use std::process::Command;
+
+#[iex]
+fn check(x: u32) -> Result<u32, String> {
+    if x < 10 {
+        Ok(x)
+    } else {
+        Err(format!("{x} is too large"))
+    }
+}
+
+#[test]
+fn unwrap_or_abort_ok() {
+    assert_eq!(check(1).unwrap_or_abort(), 1);
+}
+
+#[test]
+fn unwrap_or_abort_err() {
+    if std::env::var_os("IEX_TEST_ABORT_CHILD").is_some() {
+        check(11).unwrap_or_abort();
+        return;
+    }
+
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args([
+            "--exact",
+            "unwrap_or_abort_err",
+            "--nocapture",
+            "--test-threads=1",
+        ])
+        .env("IEX_TEST_ABORT_CHILD", "1")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        assert_eq!(output.status.signal(), Some(6)); // SIGABRT
+    }
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("called `Outcome::unwrap_or_abort()` on an `Err` value: \"11 is too large\""));
+}
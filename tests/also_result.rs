@@ -0,0 +1,63 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+
+#[iex(also_result = "parse_result")]
+fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    let value = s.parse::<u32>()?;
+    Ok(value + 1)
+}
+
+#[iex]
+fn parse_twice(a: &str, b: &str) -> Result<u32, std::num::ParseIntError> {
+    Ok(parse(a)? + parse(b)?)
+}
+
+#[test]
+fn free_function() {
+    assert_eq!(parse("1").into_result(), Ok(2));
+    assert_eq!(parse_result("1"), Ok(2));
+    assert_eq!(parse_result("x"), parse("x").into_result());
+    assert_eq!(parse_twice("1", "2").into_result(), Ok(5));
+}
+
+struct Counter {
+    limit: u32,
+}
+
+impl Counter {
+    #[iex(also_result = "new_result")]
+    fn new(limit: u32) -> Result<Self, String> {
+        if limit == 0 {
+            return Err("Limit must be positive".to_string());
+        }
+        Ok(Self { limit })
+    }
+
+    #[iex(also_result = "add_result")]
+    fn add(&self, (a, b): (u32, u32), mut extra: u32) -> Result<u32, String> {
+        extra += a;
+        let sum = extra.saturating_add(b);
+        if sum > self.limit {
+            Err(format!("{sum} exceeds {}", self.limit))
+        } else {
+            Ok(sum)
+        }
+    }
+}
+
+#[test]
+fn methods() {
+    let counter = Counter::new_result(10).unwrap();
+    assert_eq!(counter.add((1, 2), 3).into_result(), Ok(6));
+    assert_eq!(counter.add_result((1, 2), 3), Ok(6));
+    assert_eq!(
+        counter.add_result((5, 5), 5),
+        Err("15 exceeds 10".to_string())
+    );
+    assert!(Counter::new(0).into_result().is_err());
+    assert_eq!(
+        Counter::new_result(0).err(),
+        Some("Limit must be positive".to_string())
+    );
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, AnyOutcome};
+use std::any::TypeId;
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq)]
+struct ParseError;
+
+#[derive(Debug, PartialEq)]
+struct RangeError(u32);
+
+#[iex]
+fn parse(s: &str) -> Result<u32, ParseError> {
+    s.parse().map_err(|_| ParseError)
+}
+
+#[iex]
+fn check_range(x: u32) -> Result<u32, RangeError> {
+    if x < 100 {
+        Ok(x)
+    } else {
+        Err(RangeError(x))
+    }
+}
+
+type Handler = fn(&str) -> AnyOutcome<'_, u32>;
+
+fn dispatch(handlers: &HashMap<TypeId, Handler>, outcome: AnyOutcome<'_, u32>) -> String {
+    assert!(handlers.contains_key(&outcome.error_type_id()));
+    if outcome.is::<ParseError>() {
+        format!(
+            "{:?}",
+            outcome.downcast_into_result::<ParseError>().unwrap()
+        )
+    } else {
+        format!(
+            "{:?}",
+            outcome.downcast_into_result::<RangeError>().unwrap()
+        )
+    }
+}
+
+#[test]
+fn registry() {
+    let mut handlers: HashMap<TypeId, Handler> = HashMap::new();
+    handlers.insert(TypeId::of::<ParseError>(), |s| AnyOutcome::new(parse(s)));
+    handlers.insert(TypeId::of::<RangeError>(), |s| {
+        AnyOutcome::new(check_range(s.len() as u32 * 50))
+    });
+
+    let parse_handler = handlers[&TypeId::of::<ParseError>()];
+    let range_handler = handlers[&TypeId::of::<RangeError>()];
+
+    assert_eq!(dispatch(&handlers, parse_handler("12")), "Ok(12)");
+    assert_eq!(dispatch(&handlers, parse_handler("x")), "Err(ParseError)");
+    assert_eq!(dispatch(&handlers, range_handler("a")), "Ok(50)");
+    assert_eq!(
+        dispatch(&handlers, range_handler("abc")),
+        "Err(RangeError(150))"
+    );
+}
+
+#[test]
+fn wrong_type() {
+    let outcome = AnyOutcome::new(parse("x"));
+    assert!(!outcome.is::<RangeError>());
+    assert_eq!(outcome.downcast_into_result::<RangeError>(), None);
+}
+
+#[test]
+fn plain_result() {
+    let outcome = AnyOutcome::new(Err::<u32, _>(RangeError(1)));
+    assert_eq!(outcome.error_type_id(), TypeId::of::<RangeError>());
+    assert_eq!(
+        outcome.downcast_into_result::<RangeError>(),
+        Some(Err(RangeError(1)))
+    );
+}
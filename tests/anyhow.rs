@@ -1,3 +1,5 @@
+#![cfg(feature = "anyhow")]
+
 use anyhow::{anyhow, bail, Result};
 use iex::{iex, Context, Outcome};
 
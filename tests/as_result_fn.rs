@@ -0,0 +1,76 @@
+#![cfg(feature = "std")]
+#![allow(clippy::too_many_arguments)]
+
+use iex::{as_result_fn, iex};
+
+#[iex]
+fn constant() -> Result<i32, String> {
+    Ok(7)
+}
+
+#[iex]
+fn parse(s: &str) -> Result<i32, String> {
+    s.parse().map_err(|_| format!("invalid number: {s}"))
+}
+
+#[iex]
+fn sum(a: i32, b: i32, c: i32) -> Result<i32, String> {
+    a.checked_add(b)
+        .and_then(|x| x.checked_add(c))
+        .ok_or_else(|| "overflow".to_string())
+}
+
+#[iex]
+fn sum8(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8, h: u8) -> Result<u8, String> {
+    [b, c, d, e, f, g, h]
+        .into_iter()
+        .try_fold(a, u8::checked_add)
+        .ok_or_else(|| "overflow".to_string())
+}
+
+fn call0(f: impl Fn() -> Result<i32, String>) -> Result<i32, String> {
+    f()
+}
+
+fn parse_all(values: &[&str], f: impl Fn(&str) -> Result<i32, String>) -> Result<Vec<i32>, String> {
+    values.iter().map(|s| f(s)).collect()
+}
+
+fn call3(f: impl Fn(i32, i32, i32) -> Result<i32, String>) -> Result<i32, String> {
+    f(1, 2, 3)
+}
+
+fn call8(f: impl Fn(u8, u8, u8, u8, u8, u8, u8, u8) -> Result<u8, String>) -> Result<u8, String> {
+    f(1, 2, 3, 4, 5, 6, 7, 8)
+}
+
+#[test]
+fn nullary() {
+    assert_eq!(call0(as_result_fn!(constant, 0)), Ok(7));
+}
+
+#[test]
+fn borrowed_argument() {
+    assert_eq!(
+        parse_all(&["1", "2"], as_result_fn!(parse, 1)),
+        Ok(vec![1, 2])
+    );
+    assert_eq!(
+        parse_all(&["1", "x"], as_result_fn!(parse, 1)),
+        Err("invalid number: x".to_string())
+    );
+}
+
+#[test]
+fn several_arguments() {
+    assert_eq!(call3(as_result_fn!(sum, 3)), Ok(6));
+    assert_eq!(call8(as_result_fn!(sum8, 8)), Ok(36));
+}
+
+#[test]
+fn each_call_resolves() {
+    let f = |a: i32, b: i32, c: i32| sum(a, b, c);
+    let adapted = as_result_fn!(f, 3);
+    assert_eq!(adapted(i32::MAX, 1, 0), Err("overflow".to_string()));
+    assert_eq!(adapted(1, 1, 1), Ok(3));
+}
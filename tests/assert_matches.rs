@@ -0,0 +1,59 @@
+#![cfg(feature = "std")]
+
+use iex::{assert_err_matches, assert_ok_matches, iex};
+
+#[derive(Debug)]
+enum ParseError {
+    Empty,
+    Invalid(char),
+}
+
+#[iex]
+fn parse(s: &str) -> Result<u32, ParseError> {
+    let first = s.chars().next().ok_or(ParseError::Empty)?;
+    s.parse().map_err(|_| ParseError::Invalid(first))
+}
+
+#[test]
+fn ok_matches() {
+    assert_ok_matches!(parse("12"), 12);
+    assert_ok_matches!(parse("12"), 10..=20,);
+    assert_ok_matches!(parse("12"), x if x % 2 == 0);
+    assert_ok_matches!(parse("12"), x if x % 2 == 0, "{} is odd", 12);
+}
+
+#[test]
+fn err_matches() {
+    assert_err_matches!(parse(""), ParseError::Empty);
+    assert_err_matches!(parse("x1"), ParseError::Invalid('x'));
+    assert_err_matches!(parse("x1"), ParseError::Invalid(c) if c.is_alphabetic(), "{c:?}", c = 'x');
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion failed: `parse(\"x\")` matches `Ok(_)`\n  result: Err(Invalid('x'))"
+)]
+fn ok_matches_err() {
+    assert_ok_matches!(parse("x"), _);
+}
+
+#[test]
+#[should_panic(expected = "matches `Ok(x if x > 20)`\n  result: Ok(12)\n  too small")]
+fn ok_matches_mismatch() {
+    assert_ok_matches!(parse("12"), x if x > 20, "too small");
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion failed: `parse(\"12\")` matches `Err(ParseError::Empty)`\n  result: \
+                Ok(12)"
+)]
+fn err_matches_ok() {
+    assert_err_matches!(parse("12"), ParseError::Empty);
+}
+
+#[test]
+#[should_panic(expected = "result: Err(Invalid('x'))")]
+fn err_matches_mismatch() {
+    assert_err_matches!(parse("x"), ParseError::Empty);
+}
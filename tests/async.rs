@@ -0,0 +1,152 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+struct ThreadWaker(std::thread::Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+// A minimal single-threaded executor
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+// Suspends the future once, like waiting for I/O would
+async fn yield_now() {
+    let mut yielded = false;
+    std::future::poll_fn(|cx| {
+        if yielded {
+            Poll::Ready(())
+        } else {
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+#[derive(Debug, PartialEq)]
+enum HttpError {
+    NotFound,
+    InvalidUrl,
+}
+
+#[iex]
+fn parse_url(url: &str) -> Result<&str, HttpError> {
+    url.strip_prefix("http://").ok_or(HttpError::InvalidUrl)
+}
+
+#[iex]
+async fn fetch(url: &str) -> Result<Vec<u8>, HttpError> {
+    let path = parse_url(url)?;
+    yield_now().await;
+    if path == "missing" {
+        return Err(HttpError::NotFound);
+    }
+    Ok(path.as_bytes().to_vec())
+}
+
+#[iex]
+async fn fetch_both(first: &str, second: &str) -> Result<usize, HttpError> {
+    let first = fetch(first).await?;
+    yield_now().await;
+    let second = fetch(second).await?;
+    Ok(first.len() + second.len())
+}
+
+#[test]
+fn fetch_ok() {
+    assert_eq!(
+        block_on(fetch("http://index")).into_result(),
+        Ok(b"index".to_vec())
+    );
+    assert_eq!(
+        block_on(fetch_both("http://a", "http://bc")).into_result(),
+        Ok(3)
+    );
+}
+
+#[test]
+fn fetch_err() {
+    assert_eq!(
+        block_on(fetch("ftp://index")).into_result(),
+        Err(HttpError::InvalidUrl)
+    );
+    assert_eq!(
+        block_on(fetch("http://missing")).into_result(),
+        Err(HttpError::NotFound)
+    );
+    assert_eq!(
+        block_on(fetch_both("http://a", "http://missing")).into_result(),
+        Err(HttpError::NotFound)
+    );
+}
+
+#[iex]
+fn content_len(url: &str) -> Result<usize, HttpError> {
+    // An awaited outcome can be propagated from a regular #[iex] function too
+    Ok(block_on(fetch(url))?.len())
+}
+
+#[test]
+fn await_in_sync_iex() {
+    assert_eq!(content_len("http://abc").into_result(), Ok(3));
+    assert_eq!(
+        content_len("http://missing").into_result(),
+        Err(HttpError::NotFound)
+    );
+}
+
+struct Client {
+    base: String,
+}
+
+impl Client {
+    #[iex]
+    async fn get(&self, path: &str) -> Result<Vec<u8>, HttpError> {
+        Ok(fetch(&format!("{}/{path}", self.base)).await?)
+    }
+}
+
+#[test]
+fn method() {
+    let client = Client {
+        base: "http://host".to_string(),
+    };
+    assert_eq!(
+        block_on(client.get("x")).into_result(),
+        Ok(b"host/x".to_vec())
+    );
+}
+
+#[test]
+fn resumed_on_another_thread() {
+    // The future is suspended on one thread and completed on another
+    let mut future = Box::pin(fetch_both("http://a", "http://missing"));
+    let waker = Waker::noop();
+    assert!(future
+        .as_mut()
+        .poll(&mut Context::from_waker(waker))
+        .is_pending());
+    let result = std::thread::spawn(move || block_on(future).into_result())
+        .join()
+        .unwrap();
+    assert_eq!(result, Err(HttpError::NotFound));
+}
@@ -0,0 +1,96 @@
+// Backtraces are only captured if the environment enables them, which can't be changed safely
+// while other threads are running. This test uses a custom harness, so that the environment is set
+// before any threads are spawned.
+
+#![cfg_attr(feature = "plain", allow(dead_code, unused_imports))]
+
+use iex::{iex, Outcome};
+use std::backtrace::{Backtrace, BacktraceStatus};
+
+#[derive(Debug)]
+struct TracedError {
+    message: &'static str,
+    backtrace: Backtrace,
+}
+
+#[inline(never)]
+#[iex]
+fn raising_function() -> Result<(), &'static str> {
+    Err("Failed")
+}
+
+#[iex]
+fn intermediate() -> Result<(), &'static str> {
+    raising_function()?;
+    Ok(())
+}
+
+#[iex]
+fn traced() -> Result<(), TracedError> {
+    intermediate().map_err_backtrace(|message, backtrace| TracedError { message, backtrace })
+}
+
+fn backtrace_is_captured_at_raise_point() {
+    let err = traced().into_result().unwrap_err();
+    assert_eq!(err.message, "Failed");
+    assert_eq!(err.backtrace.status(), BacktraceStatus::Captured);
+    assert!(err.backtrace.to_string().contains("raising_function"));
+
+    // Plain results get a backtrace too
+    let err = Err::<(), _>("Plain")
+        .map_err_backtrace(|message, backtrace| TracedError { message, backtrace })
+        .into_result()
+        .unwrap_err();
+    assert_eq!(err.backtrace.status(), BacktraceStatus::Captured);
+}
+
+fn last_backtrace_after_into_result() {
+    assert!(intermediate().into_result().is_err());
+    let backtrace = iex::last_backtrace().unwrap();
+    assert_eq!(backtrace.status(), BacktraceStatus::Captured);
+    assert!(backtrace.to_string().contains("raising_function"));
+    assert!(iex::last_backtrace().is_none());
+
+    // Consumed during propagation
+    assert!(traced().into_result().is_err());
+    assert!(iex::last_backtrace().is_none());
+}
+
+#[iex]
+fn recovers() -> Result<(), &'static str> {
+    intermediate().or_else(|_| Ok::<(), &'static str>(()))?;
+    assert_eq!(intermediate().unwrap_or(()), ());
+    Ok(())
+}
+
+fn recovered_errors_keep_last_backtrace() {
+    let _ = iex::last_backtrace();
+    assert_eq!(recovers().into_result(), Ok(()));
+    assert!(iex::last_backtrace().is_none());
+}
+
+fn into_result_with_backtrace() {
+    let caught = intermediate().into_result_with_backtrace().unwrap_err();
+    assert_eq!(*caught.error(), "Failed");
+    let backtrace = caught.backtrace().unwrap();
+    assert_eq!(backtrace.status(), BacktraceStatus::Captured);
+    assert!(backtrace.to_string().contains("raising_function"));
+    assert!(iex::last_backtrace().is_none());
+
+    let caught = Err::<(), _>("Plain")
+        .into_result_with_backtrace()
+        .unwrap_err();
+    assert!(caught.backtrace().is_none());
+}
+
+fn main() {
+    std::env::set_var("RUST_LIB_BACKTRACE", "1");
+
+    #[cfg(not(feature = "plain"))]
+    {
+        backtrace_is_captured_at_raise_point();
+        last_backtrace_after_into_result();
+        recovered_errors_keep_last_backtrace();
+        into_result_with_backtrace();
+    }
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+
+type Trail = Vec<&'static str>;
+
+#[iex]
+fn leaf(x: u32) -> Result<u32, String> {
+    if x == 0 {
+        Err("Zero".to_string())
+    } else {
+        Ok(x)
+    }
+}
+
+#[iex]
+fn parse(x: u32) -> Result<u32, (String, Trail)> {
+    leaf(x).breadcrumb("parse")
+}
+
+#[iex]
+fn validate(x: u32) -> Result<u32, (String, Trail)> {
+    let value = parse(x).push_breadcrumb("validate")?;
+    Ok(value + 1)
+}
+
+#[iex]
+fn load(x: u32) -> Result<u32, (String, Trail)> {
+    Ok(validate(x).push_breadcrumb("load")?)
+}
+
+#[test]
+fn breadcrumb_trail() {
+    assert_eq!(load(1).into_result(), Ok(2));
+    assert_eq!(
+        load(0).into_result(),
+        Err(("Zero".to_string(), vec!["parse", "validate", "load"])),
+    );
+}
+
+#[test]
+fn breadcrumb_plain_result() {
+    assert_eq!(
+        Err::<(), _>(1)
+            .breadcrumb("first")
+            .push_breadcrumb("second")
+            .into_result(),
+        Err((1, vec!["first", "second"])),
+    );
+}
@@ -0,0 +1,153 @@
+#![cfg(feature = "std")]
+#![feature(stmt_expr_attributes, proc_macro_hygiene)]
+
+use iex::{iex, Outcome};
+
+#[derive(Debug, PartialEq)]
+struct StepError(&'static str);
+
+#[iex]
+fn run_step<O: Outcome<Error = StepError>>(
+    step: impl FnOnce() -> O,
+) -> Result<O::Output, StepError> {
+    let value = step()?;
+    Ok(value)
+}
+
+#[iex]
+fn run_steps<O, P>(first: impl FnOnce() -> O, second: impl FnOnce() -> P) -> Result<u32, StepError>
+where
+    O: Outcome<Output = u32, Error = StepError>,
+    P: Outcome<Output = u32, Error = StepError>,
+{
+    Ok(run_step(first)? + run_step(second)?)
+}
+
+#[test]
+fn plain_result_closure() {
+    assert_eq!(run_step(|| Ok(1)).into_result(), Ok(1));
+    assert_eq!(
+        run_step(|| Err::<(), _>(StepError("failed"))).into_result(),
+        Err(StepError("failed"))
+    );
+}
+
+#[test]
+fn iex_closure() {
+    let step = #[iex]
+    || -> Result<u32, StepError> { Ok(2) };
+    assert_eq!(run_step(step).into_result(), Ok(2));
+
+    let step = #[iex]
+    || -> Result<u32, StepError> { Err(StepError("failed")) };
+    assert_eq!(run_step(step).into_result(), Err(StepError("failed")));
+}
+
+#[test]
+fn mixed() {
+    let iex_step = #[iex]
+    || -> Result<u32, StepError> { Ok(2) };
+    assert_eq!(run_steps(|| Ok(1), iex_step).into_result(), Ok(3));
+
+    let iex_step = #[iex]
+    || -> Result<u32, StepError> { Err(StepError("failed")) };
+    assert_eq!(
+        run_steps(|| Ok(1), iex_step).into_result(),
+        Err(StepError("failed"))
+    );
+}
+
+// Plain `?` converts errors with `From`, not `Into`
+#[cfg(not(feature = "plain"))]
+impl From<&'static str> for StepError {
+    fn from(message: &'static str) -> Self {
+        Self(message)
+    }
+}
+
+#[cfg(not(feature = "plain"))]
+#[iex]
+fn run_converting_step<O: Outcome>(step: impl FnOnce() -> O) -> Result<O::Output, StepError>
+where
+    O::Error: Into<StepError>,
+{
+    let value = step()?;
+    Ok(value)
+}
+
+#[cfg(not(feature = "plain"))]
+#[test]
+fn converting() {
+    let step = #[iex]
+    || -> Result<u32, &'static str> { Err("failed") };
+    assert_eq!(
+        run_converting_step(step).into_result(),
+        Err(StepError("failed"))
+    );
+    assert_eq!(
+        run_converting_step(|| Ok::<_, StepError>(1)).into_result(),
+        Ok(1)
+    );
+}
+
+#[iex]
+fn for_each_word(
+    text: &str,
+    f: impl Fn(&str) -> Result<(), StepError>,
+) -> Result<usize, StepError> {
+    let mut count = 0;
+    for word in text.split(' ') {
+        f(word)?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+#[iex]
+fn first_word(text: &str, f: fn(&str) -> Result<&str, StepError>) -> Result<&str, StepError> {
+    let word = text.split(' ').next().unwrap_or("");
+    f(word)
+}
+
+#[iex]
+fn each_word_dyn(
+    text: &str,
+    f: &mut dyn FnMut(&str) -> Result<(), StepError>,
+) -> Result<(), StepError> {
+    for word in text.split(' ') {
+        f(word)?;
+    }
+    Ok(())
+}
+
+fn non_empty(word: &str) -> Result<&str, StepError> {
+    if word.is_empty() {
+        Err(StepError("empty word"))
+    } else {
+        Ok(word)
+    }
+}
+
+#[test]
+fn borrowing_callbacks() {
+    let check = |word: &str| non_empty(word).map(|_| ());
+    assert_eq!(for_each_word("a b", check).into_result(), Ok(2));
+    assert_eq!(
+        for_each_word("a  b", check).into_result(),
+        Err(StepError("empty word"))
+    );
+
+    assert_eq!(first_word("a b", non_empty).into_result(), Ok("a"));
+    assert_eq!(
+        first_word(" b", non_empty).into_result(),
+        Err(StepError("empty word"))
+    );
+
+    let mut words = Vec::new();
+    let mut collect = |word: &str| {
+        words.push(word.to_string());
+        Ok(())
+    };
+    assert_eq!(each_word_dyn("a b", &mut collect).into_result(), Ok(()));
+    assert_eq!(words, ["a", "b"]);
+}
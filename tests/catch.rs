@@ -0,0 +1,94 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[iex]
+fn parse(s: &str) -> Result<u32, String> {
+    s.parse().map_err(|_| format!("{s} is not a number"))
+}
+
+#[test]
+fn ok_and_err() {
+    assert_eq!(iex::catch(|| parse("12")), Ok(12));
+    assert_eq!(
+        iex::catch(|| parse("x")),
+        Err("x is not a number".to_string())
+    );
+}
+
+#[test]
+fn plain_result() {
+    assert_eq!(iex::catch(|| "7".parse::<u8>()), Ok(7));
+}
+
+#[test]
+fn foreign_panic_is_resumed() {
+    let payload = catch_unwind(AssertUnwindSafe(|| {
+        iex::catch(|| -> Result<(), String> { panic!("bug") })
+    }))
+    .unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"bug"));
+}
+
+#[iex]
+fn catches_inside(s: &str) -> Result<u32, String> {
+    let inner = iex::catch(|| parse("inner"));
+    assert_eq!(inner, Err("inner is not a number".to_string()));
+    parse(s)
+}
+
+#[iex]
+fn catches_while_propagating(s: &str) -> Result<u32, String> {
+    let value = parse(s).map_err(|err| {
+        let inner = iex::catch(|| parse("inner"));
+        assert_eq!(inner, Err("inner is not a number".to_string()));
+        format!("outer: {err}")
+    })?;
+    Ok(value)
+}
+
+#[test]
+fn nested_catch() {
+    assert_eq!(catches_inside("1").into_result(), Ok(1));
+    assert_eq!(
+        catches_inside("x").into_result(),
+        Err("x is not a number".to_string())
+    );
+    assert_eq!(
+        iex::catch(|| catches_while_propagating("x")),
+        Err("outer: x is not a number".to_string())
+    );
+}
+
+// The sentinel of another catch_unwind-based layer
+struct Sentinel;
+
+fn run_layer<R>(f: impl FnOnce() -> R) -> Option<R> {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => Some(value),
+        Err(payload) if payload.is::<Sentinel>() => None,
+        Err(payload) => std::panic::resume_unwind(payload),
+    }
+}
+
+#[iex]
+fn aborts_layer(s: &str) -> Result<u32, String> {
+    if s == "abort" {
+        std::panic::resume_unwind(Box::new(Sentinel));
+    }
+    parse(s)
+}
+
+#[test]
+fn foreign_sentinel_passes_through() {
+    // iex doesn't intercept the other layer's unwinds...
+    assert_eq!(run_layer(|| iex::catch(|| aborts_layer("abort"))), None);
+    assert_eq!(run_layer(|| aborts_layer("abort").into_result()), None);
+    // ...and the other layer never sees iex errors
+    assert_eq!(
+        run_layer(|| aborts_layer("x").into_result()),
+        Some(Err("x is not a number".to_string()))
+    );
+    assert_eq!(run_layer(|| iex::catch(|| aborts_layer("1"))), Some(Ok(1)));
+}
@@ -0,0 +1,131 @@
+#![cfg(all(feature = "std", feature = "nightly"))]
+#![feature(try_trait_v2, try_trait_v2_residual)]
+
+use iex::{iex, ErrorResidual, Outcome};
+use std::convert::Infallible;
+use std::ops::{ControlFlow, FromResidual, Residual, Try};
+
+#[derive(Debug, PartialEq)]
+struct ParseError(String);
+
+#[iex]
+fn parse(s: &str) -> Result<u32, ParseError> {
+    s.parse()
+        .map_err(|_| ParseError(format!("{s:?} is not a number")))
+}
+
+#[derive(Debug, PartialEq)]
+struct AppError(String);
+
+impl From<ParseError> for AppError {
+    fn from(err: ParseError) -> Self {
+        AppError(err.0)
+    }
+}
+
+#[test]
+fn into_converted_result() {
+    let ok: Result<u32, AppError> = parse("1").catch_into();
+    assert_eq!(ok, Ok(1));
+    let err: Result<u32, AppError> = parse("x").catch_into();
+    assert_eq!(err, Err(AppError("\"x\" is not a number".to_string())));
+}
+
+#[test]
+fn into_control_flow() {
+    let flow: ControlFlow<AppError, u32> = parse("1").catch_into();
+    assert_eq!(flow, ControlFlow::Continue(1));
+    let flow: ControlFlow<AppError, u32> = parse("x").catch_into();
+    assert_eq!(
+        flow,
+        ControlFlow::Break(AppError("\"x\" is not a number".to_string()))
+    );
+}
+
+fn first_large(strs: &[&str]) -> ControlFlow<ParseError, Option<u32>> {
+    for s in strs {
+        let value: u32 = parse(s).catch_into::<ControlFlow<ParseError, u32>>()?;
+        if value > 10 {
+            return ControlFlow::Continue(Some(value));
+        }
+    }
+    ControlFlow::Continue(None)
+}
+
+#[test]
+fn control_flow_propagates() {
+    assert_eq!(
+        first_large(&["1", "40", "x"]),
+        ControlFlow::Continue(Some(40))
+    );
+    assert_eq!(first_large(&["1", "3"]), ControlFlow::Continue(None));
+    assert_eq!(
+        first_large(&["1", "x", "40"]),
+        ControlFlow::Break(ParseError("\"x\" is not a number".to_string()))
+    );
+}
+
+// A custom result-like type
+#[derive(Debug, PartialEq)]
+enum Lookup<T> {
+    Found(T),
+    Missing(String),
+}
+
+impl<T> Try for Lookup<T> {
+    type Output = T;
+    type Residual = Lookup<Infallible>;
+
+    fn from_output(output: T) -> Self {
+        Lookup::Found(output)
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, T> {
+        match self {
+            Lookup::Found(value) => ControlFlow::Continue(value),
+            Lookup::Missing(reason) => ControlFlow::Break(Lookup::Missing(reason)),
+        }
+    }
+}
+
+impl<T> Residual<T> for Lookup<Infallible> {
+    type TryType = Lookup<T>;
+}
+
+impl<T> FromResidual<Lookup<Infallible>> for Lookup<T> {
+    fn from_residual(residual: Lookup<Infallible>) -> Self {
+        match residual {
+            Lookup::Missing(reason) => Lookup::Missing(reason),
+        }
+    }
+}
+
+impl ErrorResidual<ParseError> for Lookup<Infallible> {
+    fn from_error(error: ParseError) -> Self {
+        Lookup::Missing(error.0)
+    }
+}
+
+#[test]
+fn into_custom_try_type() {
+    assert_eq!(parse("5").catch_into::<Lookup<u32>>(), Lookup::Found(5));
+    assert_eq!(
+        parse("x").catch_into::<Lookup<u32>>(),
+        Lookup::Missing("\"x\" is not a number".to_string())
+    );
+}
+
+fn sum(a: &str, b: &str) -> Lookup<u32> {
+    let a = parse(a).catch_into::<Lookup<u32>>()?;
+    let b = parse(b).catch_into::<Lookup<u32>>()?;
+    Lookup::Found(a + b)
+}
+
+#[test]
+fn custom_try_type_propagates() {
+    assert_eq!(sum("1", "2"), Lookup::Found(3));
+    assert_eq!(
+        sum("1", "y"),
+        Lookup::Missing("\"y\" is not a number".to_string())
+    );
+}
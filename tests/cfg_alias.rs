@@ -0,0 +1,61 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+
+#[cfg(feature = "anyhow")]
+#[derive(Debug, PartialEq)]
+struct ErrA;
+
+#[cfg(not(feature = "anyhow"))]
+#[derive(Debug, PartialEq)]
+struct ErrB(&'static str);
+
+// Any feature would do, as long as the tests are run both with and without it
+#[cfg(feature = "anyhow")]
+type MyResult<T> = Result<T, ErrA>;
+#[cfg(not(feature = "anyhow"))]
+type MyResult<T> = Result<T, ErrB>;
+
+#[cfg(feature = "anyhow")]
+fn make_error() -> ErrA {
+    ErrA
+}
+#[cfg(not(feature = "anyhow"))]
+fn make_error() -> ErrB {
+    ErrB("failed")
+}
+
+#[iex]
+fn check(x: i32) -> MyResult<i32> {
+    if x < 0 {
+        Err(make_error())
+    } else {
+        Ok(x)
+    }
+}
+
+#[iex]
+fn check_twice(x: i32) -> MyResult<i32> {
+    Ok(check(x)? + check(x - 1)?)
+}
+
+#[test]
+fn cfg_selected_alias() {
+    assert_eq!(check_twice(2).into_result(), Ok(3));
+    assert_eq!(check_twice(0).into_result(), Err(make_error()));
+}
+
+struct Checker;
+
+impl Checker {
+    #[iex]
+    fn check(&self, x: i32) -> MyResult<i32> {
+        check(x)
+    }
+}
+
+#[test]
+fn cfg_selected_alias_method() {
+    assert_eq!(Checker.check(1).into_result(), Ok(1));
+    assert_eq!(Checker.check(-1).into_result(), Err(make_error()));
+}
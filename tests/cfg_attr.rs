@@ -0,0 +1,61 @@
+#![cfg(feature = "std")]
+
+// `all()` is always true and `any()` is always false, so both configurations of a conditionally
+// applied #[iex] are compiled in a single build
+use iex::{iex, Outcome};
+
+#[cfg_attr(all(), iex)]
+fn with_iex(x: u32) -> Result<u32, &'static str> {
+    x.checked_sub(1).ok_or("Underflow")
+}
+
+#[cfg_attr(any(), iex)]
+fn without_iex(x: u32) -> Result<u32, &'static str> {
+    x.checked_sub(1).ok_or("Underflow")
+}
+
+struct Counter(u32);
+
+impl Counter {
+    #[cfg_attr(all(), iex)]
+    fn decrement_with_iex(&mut self) -> Result<u32, &'static str> {
+        self.0 = with_iex(self.0)?;
+        Ok(self.0)
+    }
+
+    #[cfg_attr(any(), iex)]
+    fn decrement_without_iex(&mut self) -> Result<u32, &'static str> {
+        self.0 = without_iex(self.0)?;
+        Ok(self.0)
+    }
+}
+
+// The call sites are identical in both configurations
+#[iex]
+fn sum(x: u32, y: u32) -> Result<u32, &'static str> {
+    Ok(with_iex(x)? + without_iex(y)?)
+}
+
+#[test]
+fn call_sites() {
+    assert_eq!(sum(2, 3).into_result(), Ok(3));
+    assert_eq!(sum(0, 3).into_result(), Err("Underflow"));
+    assert_eq!(sum(2, 0).into_result(), Err("Underflow"));
+
+    assert_eq!(with_iex(1).into_result(), Ok(0));
+    assert_eq!(without_iex(1).into_result(), Ok(0));
+    assert_eq!(with_iex(0).map_err(str::len).into_result(), Err(9));
+    assert_eq!(without_iex(0).map_err(str::len).into_result(), Err(9));
+}
+
+#[test]
+fn methods() {
+    let mut counter = Counter(2);
+    assert_eq!(counter.decrement_with_iex().into_result(), Ok(1));
+    assert_eq!(counter.decrement_without_iex().into_result(), Ok(0));
+    assert_eq!(counter.decrement_with_iex().into_result(), Err("Underflow"));
+    assert_eq!(
+        counter.decrement_without_iex().into_result(),
+        Err("Underflow")
+    );
+}
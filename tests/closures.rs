@@ -1,3 +1,4 @@
+#![cfg(feature = "std")]
 #![feature(stmt_expr_attributes, proc_macro_hygiene)]
 
 use iex::{iex, Outcome};
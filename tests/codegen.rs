@@ -0,0 +1,62 @@
+#![cfg(feature = "std")]
+
+use std::process::Command;
+
+fn llvm_ir() -> String {
+    let target_dir = concat!(env!("CARGO_TARGET_TMPDIR"), "/codegen");
+    let output = Command::new(env!("CARGO"))
+        .args([
+            "rustc",
+            "--quiet",
+            "--release",
+            "--example",
+            "codegen",
+            "--target-dir",
+        ])
+        .arg(target_dir)
+        .args(["--", "--emit=llvm-ir"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    // The file name contains a hash, so pick the most recent one
+    let path = std::fs::read_dir(format!("{target_dir}/release/examples"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "ll"))
+        .max_by_key(|path| path.metadata().unwrap().modified().unwrap())
+        .unwrap();
+    std::fs::read_to_string(path).unwrap()
+}
+
+fn function_body<'a>(ir: &'a str, name: &str) -> &'a str {
+    let start = ir
+        .lines()
+        .find(|line| line.starts_with("define") && line.contains(&format!("@{name}(")))
+        .unwrap_or_else(|| panic!("{name} not found"));
+    let start = ir.find(start).unwrap();
+    let end = start + ir[start..].find("\n}\n").unwrap();
+    &ir[start..end]
+}
+
+#[test]
+fn no_catch_frame_without_raise() {
+    let ir = llvm_ir();
+    // The control: a body that may raise is resolved inside a catch frame
+    assert!(function_body(&ir, "resolve_may_raise").contains("landingpad"));
+    let body = function_body(&ir, "resolve_never_raises");
+    assert!(!body.contains("landingpad"), "{body}");
+    assert!(!body.contains("personality"), "{body}");
+}
+
+#[test]
+fn constant_input_folds() {
+    let ir = llvm_ir();
+    let body = function_body(&ir, "resolve_constant");
+    assert!(body.contains("ret i32 42"), "{body}");
+    assert!(!body.contains("call"), "{body}");
+}
@@ -0,0 +1,434 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+
+#[iex]
+fn parse(s: &str) -> Result<u32, String> {
+    s.parse().map_err(|_| format!("{s} is not a number"))
+}
+
+#[iex]
+fn halve(x: u32) -> Result<u32, String> {
+    if x & 1 == 0 {
+        Ok(x / 2)
+    } else {
+        Err(format!("{x} is odd"))
+    }
+}
+
+#[test]
+fn map() {
+    assert_eq!(parse("21").map(|x| x * 2).into_result(), Ok(42));
+    assert_eq!(
+        parse("x").map(|_| -> u32 { unreachable!() }).into_result(),
+        Err("x is not a number".to_string()),
+    );
+}
+
+struct Header {
+    len: usize,
+}
+
+#[iex]
+fn parse_header(buf: &[u8]) -> Result<Header, String> {
+    match buf.first() {
+        Some(&len) => Ok(Header { len: len as usize }),
+        None => Err("Empty buffer".to_string()),
+    }
+}
+
+#[iex]
+fn body_len(buf: &[u8], calls: &std::cell::Cell<u32>) -> Result<usize, String> {
+    let len = parse_header(buf).map(|h| {
+        calls.set(calls.get() + 1);
+        h.len
+    })?;
+    Ok(len)
+}
+
+#[test]
+fn map_in_iex() {
+    let calls = std::cell::Cell::new(0);
+    assert_eq!(body_len(&[3, 1, 2, 3], &calls).into_result(), Ok(3));
+    assert_eq!(calls.get(), 1);
+    assert_eq!(
+        body_len(&[], &calls).into_result(),
+        Err("Empty buffer".to_string()),
+    );
+    // The mapping closure is not called on error
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn and_then() {
+    assert_eq!(parse("42").and_then(halve).into_result(), Ok(21));
+    assert_eq!(
+        parse("43").and_then(halve).into_result(),
+        Err("43 is odd".to_string()),
+    );
+    assert_eq!(
+        parse("x")
+            .and_then(|_| -> Result<u32, String> { unreachable!() })
+            .into_result(),
+        Err("x is not a number".to_string()),
+    );
+    // Plain results work on either side
+    assert_eq!(
+        Outcome::and_then(Ok::<_, String>(8), halve).into_result(),
+        Ok(4)
+    );
+    assert_eq!(
+        parse("8")
+            .and_then(|x| x.checked_sub(10).ok_or_else(|| "Underflow".to_string()))
+            .into_result(),
+        Err("Underflow".to_string()),
+    );
+}
+
+#[iex]
+fn read_len(buf: &[u8]) -> Result<usize, String> {
+    buf.first()
+        .map(|&len| len as usize)
+        .ok_or_else(|| "Missing length".to_string())
+}
+
+#[iex]
+fn read_body(buf: &[u8], len: usize) -> Result<&[u8], String> {
+    buf.get(1..1 + len)
+        .ok_or_else(|| format!("Expected {len} bytes"))
+}
+
+#[iex]
+fn read_message<'a>(buf: &'a [u8], calls: &std::cell::Cell<u32>) -> Result<&'a [u8], String> {
+    let body = read_len(buf).and_then(|n| {
+        calls.set(calls.get() + 1);
+        read_body(buf, n)
+    })?;
+    Ok(body)
+}
+
+#[test]
+fn and_then_in_iex() {
+    let calls = std::cell::Cell::new(0);
+    assert_eq!(
+        read_message(&[2, 7, 8, 9], &calls).into_result(),
+        Ok(&[7, 8][..])
+    );
+    assert_eq!(
+        read_message(&[5, 1], &calls).into_result(),
+        Err("Expected 5 bytes".to_string()),
+    );
+    assert_eq!(calls.get(), 2);
+    assert_eq!(
+        read_message(&[], &calls).into_result(),
+        Err("Missing length".to_string()),
+    );
+    // The closure is not called if the first outcome fails
+    assert_eq!(calls.get(), 2);
+}
+
+#[iex]
+fn chained(s: &str) -> Result<u32, usize> {
+    let value = parse(s)
+        .and_then(halve)
+        .map(|x| x + 1)
+        .map_err(|err| err.len())?;
+    Ok(value)
+}
+
+#[test]
+fn chains() {
+    assert_eq!(chained("8").into_result(), Ok(5));
+    assert_eq!(chained("7").into_result(), Err("7 is odd".len()));
+    assert_eq!(
+        chained("abc").into_result(),
+        Err("abc is not a number".len())
+    );
+}
+
+#[test]
+fn then_both_ok() {
+    assert_eq!(parse("1").then(halve(8)).into_result(), Ok(4));
+    assert_eq!(Ok::<_, String>(()).then(parse("5")).into_result(), Ok(5));
+}
+
+#[cfg(not(feature = "plain"))]
+#[test]
+fn then_first_err() {
+    use std::cell::Cell;
+
+    #[iex]
+    fn counted(calls: &Cell<u32>) -> Result<u32, String> {
+        calls.set(calls.get() + 1);
+        Ok(1)
+    }
+
+    let calls = Cell::new(0);
+    assert_eq!(
+        parse("x").then(counted(&calls)).into_result(),
+        Err("x is not a number".to_string()),
+    );
+    // The second step is constructed, but not resolved
+    assert_eq!(calls.get(), 0);
+}
+
+#[test]
+fn then_second_err() {
+    assert_eq!(
+        parse("1").then(halve(3)).into_result(),
+        Err("3 is odd".to_string()),
+    );
+}
+
+#[iex]
+fn sequenced(a: &str, b: u32) -> Result<u32, String> {
+    let value = parse(a).then(halve(b))?;
+    Ok(value + 1)
+}
+
+#[test]
+fn then_propagate() {
+    assert_eq!(sequenced("1", 4).into_result(), Ok(3));
+    assert_eq!(sequenced("1", 5).into_result(), Err("5 is odd".to_string()));
+}
+
+#[test]
+fn or_else_recovers() {
+    assert_eq!(parse("x").or_else(|_| parse("3")).into_result(), Ok(3));
+    assert_eq!(
+        parse("1")
+            .or_else(|_| -> Result<u32, String> { unreachable!() })
+            .into_result(),
+        Ok(1)
+    );
+    assert_eq!(
+        Outcome::or_else(Err::<u32, _>("x"), parse).into_result(),
+        Err("x is not a number".to_string())
+    );
+}
+
+#[test]
+fn or_else_fallback_error_propagates() {
+    assert_eq!(
+        parse("x")
+            .or_else(|err| halve(err.len() as u32))
+            .into_result(),
+        Err("17 is odd".to_string())
+    );
+    assert_eq!(
+        parse("x")
+            .or_else(|err| Err::<u32, _>(err.len()))
+            .into_result(),
+        Err(17)
+    );
+}
+
+#[iex]
+fn recovers_then_fails(s: &str) -> Result<u32, String> {
+    let recovered = parse(s).or_else(|_| parse("4"))?;
+    Ok(halve(recovered)? + halve(3)?)
+}
+
+#[test]
+fn or_else_clears_exception() {
+    // The recovered error must not leak into later resolutions
+    assert_eq!(parse("x").or_else(|_| parse("2")).into_result(), Ok(2));
+    assert!(matches!(
+        parse("5").into_result_preserving_panic(),
+        Ok(Ok(5))
+    ));
+    assert_eq!(
+        recovers_then_fails("x").into_result(),
+        Err("3 is odd".to_string())
+    );
+    assert_eq!(halve(8).into_result(), Ok(4));
+}
+
+#[derive(Debug, PartialEq)]
+struct OddError(u32);
+
+impl From<OddError> for String {
+    fn from(OddError(x): OddError) -> Self {
+        format!("{x} is odd")
+    }
+}
+
+fn halve_checked(x: u32) -> Result<u32, OddError> {
+    if x & 1 == 0 {
+        Ok(x / 2)
+    } else {
+        Err(OddError(x))
+    }
+}
+
+#[test]
+fn and_then_try() {
+    assert_eq!(
+        parse("42").and_then_try(halve_checked).into_result(),
+        Ok(21)
+    );
+    assert_eq!(
+        parse("43").and_then_try(halve_checked).into_result(),
+        Err("43 is odd".to_string())
+    );
+    assert_eq!(
+        parse("x")
+            .and_then_try(|_| -> Result<u32, OddError> { unreachable!() })
+            .into_result(),
+        Err("x is not a number".to_string())
+    );
+    // The same error type needs no conversion
+    assert_eq!(
+        parse("8")
+            .and_then_try(|x| x.checked_sub(10).ok_or("underflow".to_string()))
+            .into_result(),
+        Err("underflow".to_string())
+    );
+}
+
+#[iex]
+fn parse_then_halve(s: &str) -> Result<u32, String> {
+    let halved = parse(s).map(halve).flatten()?;
+    Ok(halved + 1)
+}
+
+#[test]
+fn flatten() {
+    assert_eq!(parse_then_halve("8").into_result(), Ok(5));
+    assert_eq!(
+        parse_then_halve("x").into_result(),
+        Err("x is not a number".to_string())
+    );
+    assert!(matches!(
+        parse_then_halve("7").into_result_preserving_panic(),
+        Ok(Err(err)) if err == "7 is odd"
+    ));
+    // Plain results work on either level
+    assert_eq!(Outcome::flatten(Ok(parse("2"))).into_result(), Ok(2));
+    assert_eq!(parse("3").map(Ok).flatten().into_result(), Ok(3));
+    assert_eq!(halve(8).into_result(), Ok(4));
+}
+
+#[cfg(not(feature = "plain"))]
+fn combinator_layers<T>(outcome: &T) -> usize {
+    std::any::type_name_of_val(outcome)
+        .matches("Combinator<")
+        .count()
+}
+
+#[cfg(not(feature = "plain"))]
+#[test]
+fn adjacent_maps_fuse() {
+    let outcome = parse("20").map(|x| x + 1).map(|x| x * 2).map(|x| x - 1);
+    assert_eq!(combinator_layers(&outcome), 1);
+    assert_eq!(outcome.into_result(), Ok(41));
+
+    let outcome = parse("x")
+        .map_err(|err| err.len())
+        .map_err(|len| len * 2)
+        .map_err(|len| len + 1);
+    assert_eq!(combinator_layers(&outcome), 1);
+    assert_eq!(
+        outcome.into_result(),
+        Err("x is not a number".len() * 2 + 1)
+    );
+}
+
+#[cfg(not(feature = "plain"))]
+#[test]
+fn mixed_maps_fuse() {
+    let outcome = parse("x")
+        .map(|x| x + 1)
+        .map_err(|err| err.len())
+        .map(|x| x * 2)
+        .map_err(|len| len + 1);
+    assert_eq!(combinator_layers(&outcome), 1);
+    assert_eq!(outcome.into_result(), Err("x is not a number".len() + 1));
+
+    let outcome = parse("3")
+        .map_err(|err| err.len())
+        .map(|x| x * 2)
+        .map(|x| x + 1);
+    assert_eq!(combinator_layers(&outcome), 1);
+    assert_eq!(outcome.into_result(), Ok(7));
+}
+
+#[cfg(not(feature = "plain"))]
+#[test]
+fn fused_map_err_panic() {
+    let result = parse("x")
+        .map_err(|err| err.len())
+        .map_err(|_| -> usize { panic!("Error handler failed") })
+        .into_result_preserving_panic();
+    let err = result.unwrap_err();
+    assert!(err.original.is_none());
+    assert_eq!(
+        err.panic.downcast_ref::<&str>(),
+        Some(&"Error handler failed")
+    );
+}
+
+#[iex]
+fn large(fill: u8) -> Result<[u8; 65536], String> {
+    if fill == 0 {
+        Err("fill must be non-zero".to_string())
+    } else {
+        Ok([fill; 65536])
+    }
+}
+
+#[test]
+fn boxed_ok() {
+    let boxed: Box<[u8; 65536]> = large(7).boxed_ok().into_result().unwrap();
+    assert!(boxed.iter().all(|&byte| byte == 7));
+    assert_eq!(
+        large(0).boxed_ok().into_result().unwrap_err(),
+        "fill must be non-zero"
+    );
+}
+
+#[iex]
+fn name(id: u32) -> Result<Box<str>, String> {
+    let name = parse(&id.to_string())?.to_string();
+    Ok(name.into_boxed_str())
+}
+
+#[test]
+fn boxed_unsized_ok() {
+    assert_eq!(name(5).into_result().as_deref(), Ok("5"));
+    let boxed: Box<Box<str>> = name(6).boxed_ok().into_result().unwrap();
+    assert_eq!(&**boxed, "6");
+}
+
+#[iex]
+fn handle_request(id: String, s: &str) -> Result<(u32, String), (String, String)> {
+    let (value, id) = parse(s).attach(id)?;
+    // Recover from the error, keeping the ID
+    let (halved, id) = halve(value)
+        .attach(id)
+        .into_result()
+        .unwrap_or_else(|(err, id)| (err.len() as u32, id));
+    Ok((halved, id))
+}
+
+#[test]
+fn attach() {
+    assert_eq!(parse("4").attach(7).into_result(), Ok((4, 7)));
+    assert_eq!(
+        parse("x").attach(7).into_result(),
+        Err(("x is not a number".to_string(), 7))
+    );
+    // The value survives propagation
+    assert_eq!(
+        handle_request("a".to_string(), "x").into_result(),
+        Err(("x is not a number".to_string(), "a".to_string()))
+    );
+    assert_eq!(
+        handle_request("b".to_string(), "8").into_result(),
+        Ok((4, "b".to_string()))
+    );
+    assert_eq!(
+        handle_request("c".to_string(), "3").into_result(),
+        Ok((8, "c".to_string()))
+    );
+}
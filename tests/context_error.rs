@@ -0,0 +1,76 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, ContextError, Outcome};
+use std::error::Error;
+
+#[iex]
+fn parse(s: &str) -> Result<u16, std::num::ParseIntError> {
+    s.parse()
+}
+
+#[iex]
+fn parse_port(s: &str) -> Result<u16, ContextError<std::num::ParseIntError>> {
+    parse(s).map_err_context("parsing port")
+}
+
+#[iex]
+fn load_config(
+    path: &str,
+    port: &str,
+) -> Result<u16, ContextError<ContextError<std::num::ParseIntError>>> {
+    parse_port(port).map_err_with_context(move || format!("loading {path}"))
+}
+
+fn chain(err: &dyn Error) -> Vec<String> {
+    let mut messages = vec![err.to_string()];
+    let mut source = err.source();
+    while let Some(err) = source {
+        messages.push(err.to_string());
+        source = err.source();
+    }
+    messages
+}
+
+#[test]
+fn context_ok() {
+    assert_eq!(load_config("config.toml", "80").into_result().unwrap(), 80);
+}
+
+#[test]
+fn source_chain() {
+    let err = load_config("config.toml", "x").into_result().unwrap_err();
+    assert_eq!(
+        chain(&err),
+        [
+            "loading config.toml",
+            "parsing port",
+            "invalid digit found in string",
+        ]
+    );
+    assert_eq!(err.source.source, "x".parse::<u16>().unwrap_err());
+    assert_eq!(
+        format!("{:?}", err.source),
+        "ContextError { context: parsing port, source: ParseIntError { kind: InvalidDigit } }"
+    );
+}
+
+#[test]
+fn with_context_is_lazy() {
+    let mut calls = 0;
+    assert!(parse("1")
+        .map_err_with_context(|| {
+            calls += 1;
+            "context"
+        })
+        .into_result()
+        .is_ok());
+    assert_eq!(calls, 0);
+    assert!(parse("x")
+        .map_err_with_context(|| {
+            calls += 1;
+            "context"
+        })
+        .into_result()
+        .is_err());
+    assert_eq!(calls, 1);
+}
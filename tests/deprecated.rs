@@ -0,0 +1,31 @@
+#![cfg(feature = "std")]
+#![deny(deprecated)]
+
+use iex::{iex, Outcome};
+
+#[deprecated = "use `checked_divide` instead"]
+#[iex]
+fn divide(a: u32, b: u32) -> Result<u32, &'static str> {
+    a.checked_div(b).ok_or("Cannot divide by zero")
+}
+
+struct Calculator;
+
+impl Calculator {
+    #[deprecated]
+    #[iex]
+    fn divide(&self, a: u32, b: u32) -> Result<u32, &'static str> {
+        #[allow(deprecated)]
+        Ok(divide(a, b)?)
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_functions_still_work() {
+    assert_eq!(divide(6, 3).into_result(), Ok(2));
+    assert_eq!(
+        Calculator.divide(1, 0).into_result(),
+        Err("Cannot divide by zero")
+    );
+}
@@ -0,0 +1,57 @@
+#![cfg(all(debug_assertions, feature = "check-unresolved", not(feature = "plain")))]
+
+use iex::{iex, send_results, try_collect_chunks, Outcome};
+
+#[iex]
+fn fallible(fail: bool) -> Result<i32, i32> {
+    if fail {
+        Err(1)
+    } else {
+        Ok(2)
+    }
+}
+
+#[test]
+fn resolved() {
+    assert_eq!(fallible(false).into_result(), Ok(2));
+    assert_eq!(fallible(true).map_err(|err| err + 1).into_result(), Err(2));
+}
+
+#[test]
+fn dropped_unresolved() {
+    let payload = std::panic::catch_unwind(|| drop(fallible(false))).unwrap_err();
+    let message = payload.downcast_ref::<&str>().unwrap();
+    assert!(message.starts_with("iex: "));
+    assert!(message.contains("into_result()"));
+}
+
+#[test]
+fn dropped_while_unwinding() {
+    #[iex]
+    fn outer() -> Result<i32, i32> {
+        let pending = fallible(false);
+        fallible(true)?;
+        pending
+    }
+    assert_eq!(outer().into_result(), Err(1));
+}
+
+#[test]
+fn send_results_disconnected() {
+    let (tx, rx) = std::sync::mpsc::channel();
+    drop(rx);
+    assert!(send_results([false, true, false].map(fallible), &tx).is_err());
+}
+
+#[test]
+fn try_collect_chunks_stopped_early() {
+    let mut chunks = try_collect_chunks::<_, _, 2, _>([false, false, true].map(fallible));
+    assert_eq!(chunks.next().unwrap().into_result(), Ok(vec![2, 2]));
+}
+
+#[test]
+fn flat_map_iter_stopped_early() {
+    let mut iter = fallible(false).flat_map_iter(|_| vec![fallible(false), fallible(true)]);
+    assert_eq!(iter.next().unwrap().into_result(), Ok(2));
+    let _unstarted = fallible(false).flat_map_iter(|_| vec![fallible(false)]);
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "std")]
+#![feature(stmt_expr_attributes, proc_macro_hygiene)]
+
+use iex::{iex, DynOutcome, Outcome};
+
+#[derive(Debug, PartialEq)]
+struct MyErr(u32);
+
+struct Handler {
+    callback: Box<dyn Fn(u32) -> DynOutcome<'static, u32, MyErr>>,
+}
+
+impl Handler {
+    #[iex]
+    fn handle_twice(&self, x: u32) -> Result<u32, MyErr> {
+        let y = (self.callback)(x)?;
+        Ok((self.callback)(y)?)
+    }
+}
+
+#[iex]
+fn check(x: u32) -> Result<u32, MyErr> {
+    if x < 10 {
+        Ok(x * 2)
+    } else {
+        Err(MyErr(x))
+    }
+}
+
+#[test]
+fn stored_function() {
+    let handler = Handler {
+        callback: Box::new(|x| check(x).boxed()),
+    };
+    assert_eq!(handler.handle_twice(2).into_result(), Ok(8));
+    assert_eq!(handler.handle_twice(6).into_result(), Err(MyErr(12)));
+}
+
+#[test]
+fn stored_closure() {
+    let limit = 5;
+    let closure = #[iex]
+    move |x: u32| -> Result<u32, MyErr> {
+        if x < limit {
+            Ok(x + 1)
+        } else {
+            Err(MyErr(x))
+        }
+    };
+    let handler = Handler {
+        callback: Box::new(move |x| closure(x).boxed()),
+    };
+    assert_eq!(handler.handle_twice(1).into_result(), Ok(3));
+    assert_eq!(handler.handle_twice(4).into_result(), Err(MyErr(5)));
+}
+
+#[test]
+fn boxed_combinators() {
+    let outcomes: Vec<DynOutcome<'_, u32, String>> = vec![
+        check(1).map_err(|e| e.0.to_string()).boxed(),
+        check(11).map_err(|e| e.0.to_string()).boxed(),
+        Ok(3).boxed(),
+    ];
+    let results: Vec<_> = outcomes.into_iter().map(Outcome::into_result).collect();
+    assert_eq!(results, [Ok(2), Err("11".to_string()), Ok(3)]);
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+
+// Deliberately doesn't implement Debug
+struct NotDebug(u32);
+
+#[iex]
+fn check(x: u32) -> Result<u32, NotDebug> {
+    if x < 10 {
+        Ok(x)
+    } else {
+        Err(NotDebug(x))
+    }
+}
+
+#[test]
+fn expect_with_ok() {
+    assert_eq!(check(1).expect_with(|_| unreachable!()), 1);
+}
+
+#[test]
+#[should_panic(expected = "Value 11 is too large")]
+fn expect_with_err() {
+    check(11).expect_with(|err| format!("Value {} is too large", err.0));
+}
+
+#[test]
+fn expect_with_is_a_normal_panic() {
+    let payload =
+        std::panic::catch_unwind(|| check(12).expect_with(|err| err.0.to_string())).unwrap_err();
+    assert_eq!(payload.downcast_ref::<String>().unwrap(), "12");
+}
+
+#[iex]
+fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    if s == "bug" {
+        panic!("Unrelated bug");
+    }
+    s.parse()
+}
+
+#[test]
+fn expect_ok() {
+    assert_eq!(parse("1").expect("Invalid number"), 1);
+}
+
+#[test]
+#[should_panic(expected = "Invalid number: ParseIntError { kind: InvalidDigit }")]
+fn expect_err() {
+    parse("x").expect("Invalid number");
+}
+
+#[test]
+fn expect_resumes_foreign_panics() {
+    let payload = std::panic::catch_unwind(|| parse("bug").expect("Invalid number")).unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"Unrelated bug"));
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "eyre")]
+
+use eyre::{bail, eyre, Result};
+use iex::{iex, Outcome, WrapErr};
+
+#[iex]
+fn returns_eyre_report() -> Result<()> {
+    bail!(r"¯\_(ツ)_/¯");
+}
+
+#[iex]
+fn wraps_eyre_report() -> Result<()> {
+    returns_eyre_report().wrap_err("In wraps_eyre_report()")
+}
+
+#[test]
+fn iex_matches_result() {
+    let expected: Result<()> =
+        eyre::WrapErr::wrap_err(Err(eyre!(r"¯\_(ツ)_/¯")), "In wraps_eyre_report()");
+
+    // Debug output includes the location where the report was created, so compare the chains
+    let chain = |report: eyre::Report| report.chain().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        chain(wraps_eyre_report().into_result().unwrap_err()),
+        chain(expected.unwrap_err()),
+    );
+}
+
+#[iex]
+fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    s.parse()
+}
+
+#[iex]
+fn parse_port(s: &str) -> Result<u32> {
+    let port = parse(s).wrap_err_with(|| format!("Invalid port {s:?}"))?;
+    Ok(port)
+}
+
+#[test]
+fn wrap_err_with() {
+    assert_eq!(parse_port("80").into_result().unwrap(), 80);
+    let report = parse_port("x").into_result().unwrap_err();
+    assert_eq!(report.to_string(), r#"Invalid port "x""#);
+    assert_eq!(
+        report.root_cause().to_string(),
+        "invalid digit found in string"
+    );
+}
+
+#[test]
+fn wrap_err_with_is_lazy() {
+    let _ = parse("1")
+        .wrap_err_with(|| -> &str { unreachable!() })
+        .into_result();
+}
+
+#[test]
+fn into_eyre() {
+    let report = parse("x").into_eyre().into_result().unwrap_err();
+    assert!(report.downcast_ref::<std::num::ParseIntError>().is_some());
+    assert_eq!(parse("1").into_eyre().into_result().unwrap(), 1);
+}
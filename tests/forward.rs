@@ -0,0 +1,81 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+use std::fmt::Display;
+
+#[iex]
+fn checked_divide(a: u32, b: u32) -> Result<u32, &'static str> {
+    a.checked_div(b).ok_or("Cannot divide by zero")
+}
+
+#[iex]
+fn forward(a: u32, b: u32) -> Result<u32, &'static str> {
+    Ok(checked_divide(a, b)?)
+}
+
+#[iex]
+fn forward_twice(a: u32, b: u32) -> Result<u32, &'static str> {
+    Ok(forward(a, b)?)
+}
+
+#[iex]
+fn forward_into(a: u32, b: u32) -> Result<u32, String> {
+    Ok(forward(a, b)?)
+}
+
+#[test]
+fn forwarding() {
+    assert_eq!(forward_twice(6, 3).into_result(), Ok(2));
+    assert_eq!(
+        forward_twice(6, 0).into_result(),
+        Err("Cannot divide by zero")
+    );
+    assert_eq!(
+        forward_into(6, 0).into_result(),
+        Err("Cannot divide by zero".to_string()),
+    );
+}
+
+#[iex]
+fn boxed(a: u32) -> Result<Box<u32>, &'static str> {
+    Ok(Box::new(checked_divide(a, 1)?))
+}
+
+#[iex]
+fn forward_unsize(a: u32) -> Result<Box<dyn Display>, &'static str> {
+    Ok(boxed(a)?)
+}
+
+#[iex]
+fn first_word(s: &str) -> Result<&str, &'static str> {
+    s.split(' ').next().ok_or("empty")
+}
+
+#[iex]
+fn forward_borrowed(s: &str) -> Result<&str, &'static str> {
+    Ok(first_word(s)?)
+}
+
+#[test]
+fn forwarding_types() {
+    assert_eq!(forward_unsize(5).into_result().unwrap().to_string(), "5");
+    assert_eq!(forward_borrowed("hello world").into_result(), Ok("hello"));
+}
+
+struct Divider(u32);
+
+impl Divider {
+    #[iex]
+    fn divide(&self, a: u32) -> Result<u32, &'static str> {
+        Ok(checked_divide(a, self.0)?)
+    }
+}
+
+#[test]
+fn forwarding_method() {
+    assert_eq!(Divider(2).divide(6).into_result(), Ok(3));
+    assert_eq!(
+        Divider(0).divide(6).into_result(),
+        Err("Cannot divide by zero")
+    );
+}
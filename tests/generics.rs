@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use iex::{iex, Outcome};
 
 #[iex]
@@ -27,3 +29,40 @@ fn generics() {
     assert_eq!(drop(123).into_result(), Ok(()));
     assert_eq!(drop_apit(123).into_result(), Ok(()));
 }
+
+#[iex]
+fn default_pair<T>() -> Result<(T, T), ()>
+where
+    T: Default + Clone,
+{
+    let value = T::default();
+    Ok((value.clone(), value))
+}
+
+#[iex]
+fn boxed_unpin<T>(value: T) -> Result<std::pin::Pin<Box<T>>, ()>
+where
+    T: Unpin,
+{
+    Ok(Box::new(value).into())
+}
+
+#[iex]
+fn uses_bounds<T>() -> Result<usize, ()>
+where
+    T: Default + Clone + PartialEq + Unpin,
+{
+    let (a, b) = default_pair::<T>()?;
+    assert!(a == b);
+    Ok(std::mem::size_of_val(&*boxed_unpin(a)?))
+}
+
+#[test]
+fn where_clause() {
+    assert_eq!(
+        default_pair::<String>().into_result(),
+        Ok((String::new(), String::new()))
+    );
+    assert_eq!(*boxed_unpin(5).into_result().unwrap(), 5);
+    assert_eq!(uses_bounds::<u64>().into_result(), Ok(8));
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+use std::cell::RefCell;
+
+struct Guard<'a>(&'a RefCell<Vec<&'static str>>);
+
+impl Drop for Guard<'_> {
+    fn drop(&mut self) {
+        self.0.borrow_mut().push("guard dropped");
+    }
+}
+
+#[iex]
+fn check(x: u32, log: &RefCell<Vec<&'static str>>) -> Result<u32, String> {
+    log.borrow_mut().push("resolved");
+    if x % 2 == 1 {
+        Err(format!("{x} is odd"))
+    } else {
+        Ok(x)
+    }
+}
+
+#[test]
+fn with_guard_ok() {
+    let log = RefCell::new(Vec::new());
+    let result = check(2, &log)
+        .with_guard(Guard(&log), |value, guard| {
+            guard.0.borrow_mut().push("used");
+            value * 10
+        })
+        .into_result();
+    log.borrow_mut().push("returned");
+    assert_eq!(result, Ok(20));
+    assert_eq!(
+        *log.borrow(),
+        ["resolved", "used", "guard dropped", "returned"]
+    );
+}
+
+#[test]
+fn with_guard_err() {
+    let log = RefCell::new(Vec::new());
+    let result = check(3, &log)
+        .with_guard(Guard(&log), |_, _| -> u32 { unreachable!() })
+        .map_err(|err| {
+            log.borrow_mut().push("mapped");
+            err
+        })
+        .into_result();
+    log.borrow_mut().push("returned");
+    assert_eq!(result, Err("3 is odd".to_string()));
+    // The guard is dropped during propagation, before outer error handlers run
+    assert_eq!(
+        *log.borrow(),
+        ["resolved", "guard dropped", "mapped", "returned"]
+    );
+}
+
+// Dropping an unresolved outcome is reported by the `check-unresolved` feature
+#[cfg(not(any(feature = "plain", feature = "check-unresolved")))]
+#[test]
+fn with_guard_unresolved() {
+    let log = RefCell::new(Vec::new());
+    drop(check(2, &log).with_guard(Guard(&log), |value, _| value));
+    assert_eq!(*log.borrow(), ["guard dropped"]);
+}
+
+#[cfg(feature = "testing")]
+#[iex]
+fn sum_even(values: &[u32], counters: &std::cell::Cell<(u64, u64)>) -> Result<u32, String> {
+    let log = RefCell::new(Vec::new());
+    let mut sum = 0;
+    for &x in values {
+        // Odd values are skipped after being counted
+        if let Ok(x) = check(x, &log).count_paths(counters).into_result() {
+            sum += x;
+        }
+    }
+    // Propagated errors are counted too
+    check(sum + 1, &log).count_paths(counters)?;
+    Ok(sum)
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn count_paths() {
+    let counters = std::cell::Cell::new((0, 0));
+    let values: Vec<u32> = (0..100).collect();
+    assert_eq!(
+        sum_even(&values, &counters).into_result(),
+        Err("2451 is odd".to_string())
+    );
+    assert_eq!(counters.get(), (50, 51));
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, iex_closure, Outcome};
+
+#[iex]
+fn checked_divide(a: u32, b: u32) -> Result<u32, &'static str> {
+    a.checked_div(b).ok_or("Cannot divide by zero")
+}
+
+#[test]
+fn closure() {
+    let divide_by_two =
+        iex_closure!(|a: u32| -> Result<u32, &'static str> { Ok(checked_divide(a, 2)?) });
+    assert_eq!(divide_by_two(246).into_result(), Ok(123));
+
+    let divide_by_zero = iex_closure!(|a: u32| -> Result<u32, &'static str> {
+        checked_divide(a, 0)?;
+        Ok(a)
+    });
+    assert_eq!(
+        divide_by_zero(1).into_result(),
+        Err("Cannot divide by zero")
+    );
+}
+
+#[test]
+fn borrowed_capture() {
+    let divisors = String::from("23");
+    let divisors = &divisors;
+    let divide_all = iex_closure!(|a: u32| -> Result<Vec<u32>, &'static str> {
+        let mut results = Vec::new();
+        for b in divisors.bytes() {
+            results.push(checked_divide(a, (b - b'0').into())?);
+        }
+        Ok(results)
+    });
+    assert_eq!(divide_all(12).into_result(), Ok(vec![6, 4]));
+    assert_eq!(divide_all(1).into_result(), Ok(vec![0, 0]));
+}
+
+#[cfg(not(feature = "plain"))]
+#[test]
+fn runs_once_when_resolved() {
+    let calls = &std::cell::Cell::new(0);
+    let counted = iex_closure!(|| -> Result<u32, &'static str> {
+        calls.set(calls.get() + 1);
+        checked_divide(6, 3)
+    });
+    let outcome = counted();
+    assert_eq!(calls.get(), 0);
+    assert_eq!(outcome.into_result(), Ok(2));
+    assert_eq!(calls.get(), 1);
+}
+
+#[iex]
+fn sum_halves(values: &[u32]) -> Result<u32, &'static str> {
+    let halve = iex_closure!(|x: u32| -> Result<u32, &'static str> { checked_divide(x, 2) });
+    let mut sum = 0;
+    for &value in values {
+        sum += halve(value)?;
+    }
+    Ok(sum)
+}
+
+#[test]
+fn in_iex() {
+    assert_eq!(sum_halves(&[2, 4, 7]).into_result(), Ok(6));
+}
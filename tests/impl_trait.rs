@@ -0,0 +1,62 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+
+#[iex]
+fn shift(input: impl Iterator<Item = u8>, by: u8) -> Result<impl Iterator<Item = u8>, String> {
+    let bytes: Vec<u8> = input.collect();
+    if let Some(b) = bytes.iter().find(|&&b| b.checked_add(by).is_none()) {
+        return Err(format!("{b} + {by} overflows"));
+    }
+    Ok(bytes.into_iter().map(move |b| b + by))
+}
+
+#[iex]
+fn roundtrip(input: &[u8]) -> Result<Vec<u8>, String> {
+    let shifted = shift(input.iter().copied(), 10)?;
+    Ok(shift(shifted, 1)?.collect())
+}
+
+#[test]
+fn impl_trait_argument_and_return() {
+    assert_eq!(roundtrip(&[1, 2, 3]).into_result(), Ok(vec![12, 13, 14]));
+    assert_eq!(
+        roundtrip(&[1, 250]).into_result(),
+        Err("250 + 10 overflows".to_string())
+    );
+    let values: Vec<u8> = shift([1, 2].into_iter(), 1)
+        .into_result()
+        .unwrap()
+        .collect();
+    assert_eq!(values, [2, 3]);
+}
+
+struct Splitter(char);
+
+impl Splitter {
+    #[iex]
+    fn split<'a>(
+        &self,
+        input: &'a str,
+        limit: impl Into<usize>,
+    ) -> Result<impl Iterator<Item = &'a str>, usize> {
+        let limit = limit.into();
+        let count = input.split(self.0).count();
+        if count > limit {
+            return Err(count);
+        }
+        Ok(input.split(self.0))
+    }
+}
+
+#[test]
+fn impl_trait_method() {
+    let splitter = Splitter(',');
+    let parts: Vec<_> = splitter
+        .split("a,b,c", 3u8)
+        .into_result()
+        .unwrap()
+        .collect();
+    assert_eq!(parts, ["a", "b", "c"]);
+    assert!(matches!(splitter.split("a,b,c", 2u8).into_result(), Err(3)));
+}
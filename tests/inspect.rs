@@ -0,0 +1,155 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+#[iex]
+fn produces(x: Result<i32, i32>) -> Result<i32, i32> {
+    x
+}
+
+#[test]
+fn inspect_ok_then_err_ok() {
+    let log = RefCell::new(Vec::new());
+    let result = produces(Ok(1))
+        .map_err(|e| {
+            log.borrow_mut().push(format!("map_err {e}"));
+            e
+        })
+        .inspect_ok_then_err(
+            |x| log.borrow_mut().push(format!("ok {x}")),
+            |e| log.borrow_mut().push(format!("err {e}")),
+        )
+        .into_result();
+    assert_eq!(result, Ok(1));
+    assert_eq!(log.into_inner(), ["ok 1"]);
+}
+
+#[test]
+fn inspect_ok_then_err_after_map_err() {
+    let log = RefCell::new(Vec::new());
+    let result = produces(Err(1))
+        .map_err(|e| {
+            log.borrow_mut().push(format!("map_err {e}"));
+            e + 1
+        })
+        .inspect_ok_then_err(
+            |x| log.borrow_mut().push(format!("ok {x}")),
+            |e| log.borrow_mut().push(format!("err {e}")),
+        )
+        .into_result();
+    assert_eq!(result, Err(2));
+    assert_eq!(log.into_inner(), ["map_err 1", "err 2"]);
+}
+
+#[test]
+fn inspect_ok_then_err_on_result() {
+    let mut seen = None;
+    let result = Err::<i32, i32>(3)
+        .inspect_ok_then_err(|_| unreachable!(), |e| seen = Some(*e))
+        .into_result();
+    assert_eq!(result, Err(3));
+    assert_eq!(seen, Some(3));
+}
+
+#[iex]
+fn transaction(log: &RefCell<Vec<&'static str>>, x: Result<i32, i32>) -> Result<i32, i32> {
+    log.borrow_mut().push("begin");
+    let value = produces(x).on_error(|| log.borrow_mut().push("rollback"))?;
+    log.borrow_mut().push("commit");
+    Ok(value)
+}
+
+#[test]
+fn on_error_ok() {
+    let log = RefCell::new(Vec::new());
+    assert_eq!(transaction(&log, Ok(1)).into_result(), Ok(1));
+    assert_eq!(log.into_inner(), ["begin", "commit"]);
+}
+
+#[test]
+fn on_error_err() {
+    let log = RefCell::new(Vec::new());
+    assert_eq!(transaction(&log, Err(1)).into_result(), Err(1));
+    assert_eq!(log.into_inner(), ["begin", "rollback"]);
+}
+
+#[test]
+fn on_error_on_result() {
+    let mut calls = 0;
+    assert_eq!(
+        Err::<(), _>(2).on_error(|| calls += 1).into_result(),
+        Err(2)
+    );
+    assert_eq!(Ok::<_, ()>(2).on_error(|| calls += 1).into_result(), Ok(2));
+    assert_eq!(calls, 1);
+}
+
+#[iex]
+fn cached_square(cache: &RefCell<HashMap<i32, i32>>, x: i32) -> Result<i32, i32> {
+    if let Some(&y) = cache.borrow().get(&x) {
+        return Ok(y);
+    }
+    let y = produces(if x < 0 { Err(x) } else { Ok(x * x) }).inspect_cache(|&y| {
+        cache.borrow_mut().insert(x, y);
+    })?;
+    Ok(y)
+}
+
+#[test]
+fn inspect_cache_populates() {
+    let cache = RefCell::new(HashMap::new());
+    assert_eq!(cached_square(&cache, 3).into_result(), Ok(9));
+    assert_eq!(cache.borrow().get(&3), Some(&9));
+    assert_eq!(cached_square(&cache, 3).into_result(), Ok(9));
+    assert_eq!(cache.borrow().len(), 1);
+}
+
+#[test]
+fn inspect_cache_skips_errors() {
+    let cache = RefCell::new(HashMap::new());
+    assert_eq!(cached_square(&cache, -2).into_result(), Err(-2));
+    assert!(cache.borrow().is_empty());
+}
+
+#[test]
+fn inspect_ok() {
+    let mut seen = None;
+    assert_eq!(
+        produces(Ok(4)).inspect(|x| seen = Some(*x)).into_result(),
+        Ok(4)
+    );
+    assert_eq!(seen, Some(4));
+}
+
+#[test]
+fn inspect_skips_err() {
+    assert_eq!(
+        produces(Err(4)).inspect(|_| unreachable!()).into_result(),
+        Err(4)
+    );
+}
+
+#[iex]
+fn fails_with(token: std::rc::Rc<()>) -> Result<(), std::rc::Rc<()>> {
+    Err(token)
+}
+
+#[iex]
+fn inspects(token: std::rc::Rc<()>, log: &RefCell<Vec<usize>>) -> Result<(), std::rc::Rc<()>> {
+    fails_with(token).inspect_err(|err| log.borrow_mut().push(std::rc::Rc::strong_count(err)))?;
+    Ok(())
+}
+
+#[test]
+fn inspect_err_propagates_original() {
+    let token = std::rc::Rc::new(());
+    let log = RefCell::new(Vec::new());
+    let err = inspects(token.clone(), &log).into_result().unwrap_err();
+    // The error was neither cloned nor dropped during inspection
+    assert_eq!(log.into_inner(), [2]);
+    assert!(std::rc::Rc::ptr_eq(&err, &token));
+    drop(err);
+    assert_eq!(std::rc::Rc::strong_count(&token), 1);
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "std")]
+
+use iex::{
+    iex,
+    io::{read_to_end_iex, write_all_iex},
+    Outcome,
+};
+use std::io::{self, Cursor, Read, Write};
+
+// Yields a chunk, gets interrupted, yields another chunk, and then fails
+struct FlakyReader {
+    calls: u32,
+}
+
+impl Read for FlakyReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.calls += 1;
+        match self.calls {
+            1 | 3 => {
+                buf[0] = b'x';
+                Ok(1)
+            }
+            2 => Err(io::ErrorKind::Interrupted.into()),
+            _ => Err(io::Error::other("Broken")),
+        }
+    }
+}
+
+struct FullWriter;
+
+impl Write for FullWriter {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Ok(0)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[iex]
+fn copy(input: &[u8], output: &mut Vec<u8>) -> Result<usize, io::Error> {
+    let bytes = read_to_end_iex(Cursor::new(input))?;
+    write_all_iex(&mut *output, &bytes)?;
+    Ok(bytes.len())
+}
+
+#[test]
+fn cursor() {
+    let mut output = Vec::new();
+    assert_eq!(copy(b"Hello", &mut output).into_result().unwrap(), 5);
+    assert_eq!(output, b"Hello");
+}
+
+#[test]
+fn failing_reader() {
+    let mut reader = FlakyReader { calls: 0 };
+    let err = read_to_end_iex(&mut reader).into_result().unwrap_err();
+    assert_eq!(err.to_string(), "Broken");
+    assert_eq!(reader.calls, 4);
+}
+
+#[test]
+fn failing_writer() {
+    let err = write_all_iex(FullWriter, b"Hello")
+        .into_result()
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+}
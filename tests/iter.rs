@@ -0,0 +1,377 @@
+#![cfg(feature = "std")]
+
+use iex::{
+    first_ok, iex, results_iter, try_collect_chunks, try_collect_indexed, try_concat,
+    try_fold_into, validate_all, IexIteratorExt, Outcome,
+};
+use std::cell::Cell;
+
+#[iex]
+fn count(n: Result<u32, String>) -> Result<u32, String> {
+    n
+}
+
+#[iex]
+fn check(x: u32) -> Result<u32, String> {
+    if x % 2 == 1 {
+        Err(format!("{x} is odd"))
+    } else {
+        Ok(x)
+    }
+}
+
+#[test]
+fn flat_map_iter_ok() {
+    let results: Vec<_> = count(Ok(4))
+        .flat_map_iter(|n| (0..n).map(check))
+        .map(Outcome::into_result)
+        .collect();
+    assert_eq!(
+        results,
+        [
+            Ok(0),
+            Err("1 is odd".to_string()),
+            Ok(2),
+            Err("3 is odd".to_string()),
+        ],
+    );
+}
+
+#[test]
+fn flat_map_iter_outer_err() {
+    let results: Vec<_> = count(Err("no count".to_string()))
+        .flat_map_iter(|n| (0..n).map(check))
+        .map(Outcome::into_result)
+        .collect();
+    assert_eq!(results, [Err("no count".to_string())]);
+}
+
+#[cfg(not(feature = "plain"))]
+#[iex]
+fn count_tracked(resolved: &Cell<bool>, n: u32) -> Result<u32, String> {
+    resolved.set(true);
+    Ok(n)
+}
+
+#[cfg(not(feature = "plain"))]
+#[test]
+fn flat_map_iter_is_lazy() {
+    let resolved = Cell::new(false);
+    let mut called = false;
+    let mut iter = count_tracked(&resolved, 0).flat_map_iter(|n| {
+        called = true;
+        (0..n).map(check)
+    });
+    assert!(!resolved.get());
+    assert!(iter.next().is_none());
+    assert!(resolved.get());
+    assert!(iter.next().is_none());
+    drop(iter);
+    assert!(called);
+}
+
+#[iex]
+fn propagates() -> Result<u32, String> {
+    let mut sum = 0;
+    for outcome in count(Ok(3)).flat_map_iter(|n| (0..n).map(|x| check(x * 2))) {
+        sum += outcome?;
+    }
+    Ok(sum)
+}
+
+#[test]
+fn flat_map_iter_propagate() {
+    assert_eq!(propagates().into_result(), Ok(6));
+}
+
+#[test]
+fn try_collect_indexed_ok() {
+    assert_eq!(
+        try_collect_indexed([0, 2, 4].map(check)).into_result(),
+        Ok(vec![0, 2, 4])
+    );
+}
+
+#[test]
+fn try_collect_indexed_err() {
+    let mut resolved = Vec::new();
+    let result = try_collect_indexed([0, 2, 5, 6, 7].into_iter().map(|x| {
+        resolved.push(x);
+        check(x)
+    }))
+    .into_result();
+    assert_eq!(result, Err((2, "5 is odd".to_string())));
+    assert_eq!(resolved, [0, 2, 5]);
+}
+
+#[iex]
+fn collects_indexed() -> Result<usize, String> {
+    let values = try_collect_indexed((0..4).map(check))
+        .map_err(|(index, err)| format!("at {index}: {err}"))?;
+    Ok(values.len())
+}
+
+#[test]
+fn try_collect_indexed_propagate() {
+    assert_eq!(
+        collects_indexed().into_result(),
+        Err("at 1: 1 is odd".to_string())
+    );
+}
+
+#[test]
+fn try_collect_iex_ok() {
+    let result: Result<Vec<u32>, String> = [0, 2, 4]
+        .map(check)
+        .into_iter()
+        .try_collect_iex()
+        .into_result();
+    assert_eq!(result, Ok(vec![0, 2, 4]));
+}
+
+struct Tracked<'a>(&'a Cell<u32>);
+
+impl Drop for Tracked<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[iex]
+fn track(x: u32, drops: &Cell<u32>) -> Result<Tracked<'_>, String> {
+    check(x)?;
+    Ok(Tracked(drops))
+}
+
+#[test]
+fn try_collect_iex_drops_partial_results() {
+    let drops = Cell::new(0);
+    let mut resolved = Vec::new();
+    let result = [0, 2, 5, 6]
+        .into_iter()
+        .map(|x| {
+            resolved.push(x);
+            track(x, &drops)
+        })
+        .try_collect_iex::<Vec<_>, _, _>()
+        .into_result();
+    assert_eq!(result.err(), Some("5 is odd".to_string()));
+    assert_eq!(resolved, [0, 2, 5]);
+    assert_eq!(drops.get(), 2);
+}
+
+#[iex]
+fn sum_checked(xs: &[u32]) -> Result<u32, String> {
+    let values: Vec<u32> = xs.iter().map(|&x| check(x)).try_collect_iex()?;
+    Ok(values.into_iter().sum())
+}
+
+#[test]
+fn try_collect_iex_propagate() {
+    assert_eq!(sum_checked(&[2, 4]).into_result(), Ok(6));
+    assert_eq!(
+        sum_checked(&[2, 3]).into_result(),
+        Err("3 is odd".to_string())
+    );
+}
+
+#[iex]
+fn fragment(x: u32) -> Result<String, String> {
+    Ok(check(x)?.to_string())
+}
+
+#[test]
+fn try_concat_ok() {
+    assert_eq!(
+        try_concat([0, 2, 4].map(fragment)).into_result(),
+        Ok("024".to_string())
+    );
+    assert_eq!(
+        try_concat(Vec::<Result<String, String>>::new()).into_result(),
+        Ok(String::new())
+    );
+}
+
+#[test]
+fn try_concat_err() {
+    let mut rendered = Vec::new();
+    let result = try_concat([0, 3, 4].into_iter().map(|x| {
+        rendered.push(x);
+        fragment(x)
+    }))
+    .into_result();
+    assert_eq!(result, Err("3 is odd".to_string()));
+    assert_eq!(rendered, [0, 3]);
+}
+
+#[iex]
+fn append(acc: &mut String, x: u32) -> Result<(), String> {
+    acc.push_str(&fragment(x)?);
+    Ok(())
+}
+
+#[test]
+fn try_fold_into_ok() {
+    let mut acc = String::new();
+    assert_eq!(
+        try_fold_into([0, 2, 4], &mut acc, append).into_result(),
+        Ok(())
+    );
+    assert_eq!(acc, "024");
+}
+
+#[test]
+fn try_fold_into_err() {
+    let mut acc = String::new();
+    let result = try_fold_into([0, 2, 5, 6], &mut acc, append).into_result();
+    assert_eq!(result, Err("5 is odd".to_string()));
+    assert_eq!(acc, "02");
+}
+
+#[test]
+fn try_fold_into_closure() {
+    let mut acc = Vec::new();
+    let result = try_fold_into([0, 2, 5, 6], &mut acc, |acc: &mut Vec<u32>, x| {
+        acc.push(x);
+        check(x).map(drop)
+    })
+    .into_result();
+    assert_eq!(result, Err("5 is odd".to_string()));
+    assert_eq!(acc, [0, 2, 5]);
+}
+
+#[test]
+fn try_collect_chunks_ok() {
+    let chunks: Vec<_> = try_collect_chunks::<_, _, 3, _>((0..8).map(|x| check(x * 2)))
+        .map(Outcome::into_result)
+        .collect();
+    assert_eq!(
+        chunks,
+        [Ok(vec![0, 2, 4]), Ok(vec![6, 8, 10]), Ok(vec![12, 14])]
+    );
+}
+
+#[iex]
+fn counted_check(resolved: &Cell<u32>, x: u32) -> Result<u32, String> {
+    resolved.set(resolved.get() + 1);
+    check(x)
+}
+
+#[cfg(not(feature = "plain"))]
+#[test]
+fn try_collect_chunks_err_in_second_chunk() {
+    let resolved = Cell::new(0);
+    let chunks: Vec<_> = try_collect_chunks::<_, _, 3, _>(
+        [0, 2, 4, 6, 7, 8, 10].map(|x| counted_check(&resolved, x)),
+    )
+    .map(Outcome::into_result)
+    .collect();
+    assert_eq!(
+        chunks,
+        [Ok(vec![0, 2, 4]), Err("7 is odd".to_string()), Ok(vec![10]),]
+    );
+    // 8 is not resolved
+    assert_eq!(resolved.get(), 6);
+}
+
+#[iex]
+fn sums_chunks(values: &[u32]) -> Result<Vec<u32>, String> {
+    let mut sums = Vec::new();
+    for chunk in try_collect_chunks::<_, _, 2, _>(values.iter().map(|&x| check(x))) {
+        sums.push(chunk?.iter().sum());
+    }
+    Ok(sums)
+}
+
+#[test]
+fn try_collect_chunks_propagate() {
+    assert_eq!(sums_chunks(&[2, 4, 6]).into_result(), Ok(vec![6, 6]));
+    assert_eq!(
+        sums_chunks(&[2, 4, 6, 9]).into_result(),
+        Err("9 is odd".to_string())
+    );
+}
+
+#[test]
+fn validate_all_no_failures() {
+    assert_eq!(validate_all([0, 2, 4].map(check)), Ok(vec![0, 2, 4]));
+    assert_eq!(validate_all(Vec::<Result<u32, String>>::new()), Ok(vec![]));
+}
+
+#[test]
+fn validate_all_one_failure() {
+    assert_eq!(
+        validate_all([0, 3, 4].map(check)),
+        Err(vec!["3 is odd".to_string()])
+    );
+}
+
+#[test]
+fn validate_all_multiple_failures() {
+    let resolved = Cell::new(0);
+    assert_eq!(
+        validate_all([7, 2, 1, 4, 5].map(|x| counted_check(&resolved, x))),
+        Err(vec![
+            "7 is odd".to_string(),
+            "1 is odd".to_string(),
+            "5 is odd".to_string(),
+        ])
+    );
+    assert_eq!(resolved.get(), 5);
+}
+
+#[cfg(not(feature = "plain"))]
+#[test]
+fn first_ok_first() {
+    let resolved = Cell::new(0);
+    assert_eq!(
+        first_ok([2, 4, 6].map(|x| counted_check(&resolved, x))),
+        Ok(2)
+    );
+    assert_eq!(resolved.get(), 1);
+}
+
+#[cfg(not(feature = "plain"))]
+#[test]
+fn first_ok_third() {
+    let resolved = Cell::new(0);
+    assert_eq!(
+        first_ok([1, 3, 4, 5].map(|x| counted_check(&resolved, x))),
+        Ok(4)
+    );
+    assert_eq!(resolved.get(), 3);
+}
+
+#[test]
+fn first_ok_all_fail() {
+    assert_eq!(
+        first_ok([1, 3].map(check)),
+        Err(vec!["1 is odd".to_string(), "3 is odd".to_string()])
+    );
+    assert_eq!(first_ok(Vec::<Result<u32, String>>::new()), Err(vec![]));
+}
+
+#[test]
+fn results_iter_each() {
+    let mut messages = Vec::new();
+    for result in results_iter([2, 3, 4].map(check)) {
+        match result {
+            Ok(x) => messages.push(format!("ok {x}")),
+            Err(err) => messages.push(err),
+        }
+    }
+    assert_eq!(messages, ["ok 2", "3 is odd", "ok 4"]);
+}
+
+#[cfg(not(feature = "plain"))]
+#[test]
+fn results_iter_is_lazy() {
+    let resolved = Cell::new(0);
+    let mut results = results_iter([2, 3, 4].map(|x| counted_check(&resolved, x)));
+    assert_eq!(resolved.get(), 0);
+    assert_eq!(results.next(), Some(Ok(2)));
+    assert_eq!(results.next(), Some(Err("3 is odd".to_string())));
+    assert_eq!(resolved.get(), 2);
+    drop(results);
+    assert_eq!(resolved.get(), 2);
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use iex::{iex, Outcome};
 use std::marker::PhantomData;
 
@@ -60,3 +62,54 @@ fn mutability() {
     assert_eq!(x, 2);
     assert_eq!(y, 1);
 }
+
+struct Picker {
+    threshold: u32,
+}
+
+impl Picker {
+    #[iex]
+    fn pick<'a>(&self, items: &'a [u32]) -> Result<&'a u32, String> {
+        items
+            .iter()
+            .find(|&&item| item > self.threshold)
+            .ok_or_else(|| format!("No items above {}", self.threshold))
+    }
+}
+
+trait Pick {
+    #[iex]
+    fn pick_first<'a, T>(&self, items: &'a [T]) -> Result<&'a T, String>;
+}
+
+impl Pick for Picker {
+    #[iex]
+    fn pick_first<'a, T>(&self, items: &'a [T]) -> Result<&'a T, String> {
+        items.first().ok_or_else(|| "Empty".to_string())
+    }
+}
+
+#[test]
+fn borrow_from_non_receiver_argument() {
+    let items = vec![1, 5, 10];
+    // The returned references outlive the picker
+    let (picked, first) = {
+        let picker = Picker { threshold: 3 };
+        (
+            picker.pick(&items).into_result(),
+            picker.pick_first(&items).into_result(),
+        )
+    };
+    assert_eq!(picked, Ok(&5));
+    assert_eq!(first, Ok(&1));
+
+    let picker = Picker { threshold: 10 };
+    assert_eq!(
+        picker.pick(&items).into_result(),
+        Err("No items above 10".to_string())
+    );
+    assert_eq!(
+        picker.pick_first::<u32>(&[]).into_result(),
+        Err("Empty".to_string())
+    );
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "std")]
+#![deny(unused_variables, unfulfilled_lint_expectations)]
+
+use iex::{iex, Outcome};
+
+#[allow(unused_variables)]
+#[iex]
+fn ignores_arguments(input: &str, retries: u32) -> Result<u32, String> {
+    let parsed = 1;
+    Ok(2)
+}
+
+#[expect(unused_variables)]
+#[iex]
+fn expects_unused(input: &str) -> Result<u32, String> {
+    Ok(3)
+}
+
+#[allow(unused_variables)]
+#[iex(also_result = "ignores_body_result")]
+fn ignores_body(input: &str) -> Result<u32, String> {
+    let unused = input.len();
+    Ok(4)
+}
+
+#[test]
+fn lint_attrs() {
+    assert_eq!(ignores_arguments("", 0).into_result(), Ok(2));
+    assert_eq!(expects_unused("").into_result(), Ok(3));
+    assert_eq!(ignores_body("").into_result(), Ok(4));
+    assert_eq!(ignores_body_result(""), Ok(4));
+}
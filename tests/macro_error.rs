@@ -3,6 +3,8 @@
 //!
 //!
 
+#![cfg(feature = "std")]
+
 use core::str;
 use iex::Outcome;
 
@@ -0,0 +1,73 @@
+#![cfg(feature = "std")]
+
+use std::process::{Command, Output};
+
+fn run_example(arg: &str) -> Output {
+    Command::new(env!("CARGO"))
+        .args(["run", "--quiet", "--example", "main", "--", arg])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn main_ok() {
+    let output = run_example("21");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "42\n");
+}
+
+#[test]
+fn main_err() {
+    let output = run_example("x");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "Error: ParseIntError { kind: InvalidDigit }\n",
+    );
+}
+
+// Only #[iex(main)] generates an eager entry point, other functions called `main` stay lazy
+#[cfg(not(feature = "plain"))]
+mod other_mains {
+    use iex::{iex, Outcome};
+    use std::cell::Cell;
+
+    thread_local! {
+        static CALLS: Cell<u32> = const { Cell::new(0) };
+    }
+
+    struct App;
+
+    impl App {
+        #[iex]
+        fn main() -> Result<u32, String> {
+            CALLS.set(CALLS.get() + 1);
+            Ok(1)
+        }
+    }
+
+    mod nested {
+        use super::*;
+
+        #[iex]
+        pub fn main() -> Result<u32, String> {
+            CALLS.set(CALLS.get() + 1);
+            Err("nested".to_string())
+        }
+    }
+
+    #[test]
+    fn unchanged() {
+        let outcome = App::main();
+        assert_eq!(CALLS.get(), 0);
+        assert_eq!(outcome.into_result(), Ok(1));
+        assert_eq!(CALLS.get(), 1);
+
+        let outcome = nested::main();
+        assert_eq!(CALLS.get(), 1);
+        assert_eq!(outcome.into_result(), Err("nested".to_string()));
+        assert_eq!(CALLS.get(), 2);
+    }
+}
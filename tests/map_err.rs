@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use iex::{iex, Outcome};
 
 #[iex]
@@ -78,3 +80,129 @@ fn owned_method() {
 fn mut_ref() {
     assert_eq!(maps_err_mut_ref(A).into_result(), Err(()));
 }
+
+#[iex]
+fn parses(s: &str) -> Result<i32, std::num::ParseIntError> {
+    s.parse()
+}
+
+#[iex]
+fn parses_to_string(s: &str) -> Result<i32, String> {
+    let value = parses(s).map_err_to_string()?;
+    Ok(value)
+}
+
+#[test]
+fn to_string() {
+    assert_eq!(parses_to_string("12").into_result(), Ok(12));
+    assert_eq!(
+        parses_to_string("x").into_result().unwrap_err(),
+        "invalid digit found in string",
+    );
+    assert_eq!(
+        Err::<(), _>(1.5).map_err_to_string().into_result(),
+        Err("1.5".to_string()),
+    );
+}
+
+#[iex]
+fn parses_erased(a: &str, b: &str) -> Result<i32, Box<dyn std::fmt::Display + Send>> {
+    let a = parses(a).erase_display()?;
+    let b = produces_err2(b).erase_display()?;
+    Ok(a + b)
+}
+
+#[test]
+fn erase_display() {
+    assert_eq!(
+        parses_erased("x", "")
+            .into_result()
+            .unwrap_err()
+            .to_string(),
+        "invalid digit found in string",
+    );
+    assert_eq!(
+        parses_erased("1", "leaked")
+            .into_result()
+            .unwrap_err()
+            .to_string(),
+        "leaked",
+    );
+}
+
+#[derive(Debug, PartialEq)]
+enum LowError {
+    Timeout,
+}
+
+#[derive(Debug, PartialEq)]
+enum MidError {
+    Low(LowError),
+}
+
+#[derive(Debug, PartialEq)]
+enum HighError {
+    Timeout,
+    Other(String),
+}
+
+impl From<LowError> for MidError {
+    fn from(err: LowError) -> Self {
+        MidError::Low(err)
+    }
+}
+
+impl From<MidError> for HighError {
+    fn from(MidError::Low(LowError::Timeout): MidError) -> Self {
+        HighError::Timeout
+    }
+}
+
+impl From<String> for HighError {
+    fn from(message: String) -> Self {
+        HighError::Other(message)
+    }
+}
+
+#[iex]
+fn times_out() -> Result<u32, LowError> {
+    Err(LowError::Timeout)
+}
+
+#[iex]
+fn doubly_wrapped(retry: bool) -> Result<u32, Result<MidError, String>> {
+    if retry {
+        Ok(times_out().map_err(|err| Ok(err.into()))?)
+    } else {
+        Err(Err("gave up".to_string()))
+    }
+}
+
+#[test]
+fn flatten_err() {
+    assert_eq!(
+        times_out()
+            .map_err(MidError::from)
+            .flatten_err::<HighError>()
+            .into_result(),
+        Err(HighError::Timeout)
+    );
+    assert_eq!(
+        doubly_wrapped(true)
+            .flatten_err_result::<HighError, _, _>()
+            .into_result(),
+        Err(HighError::Timeout)
+    );
+    assert_eq!(
+        doubly_wrapped(false)
+            .flatten_err_result::<HighError, _, _>()
+            .into_result(),
+        Err(HighError::Other("gave up".to_string()))
+    );
+    assert_eq!(
+        Ok::<_, Result<MidError, String>>(1)
+            .flatten_err_result::<HighError, _, _>()
+            .into_result(),
+        Ok(1)
+    );
+}
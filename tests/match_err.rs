@@ -0,0 +1,93 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, match_err, Outcome};
+
+#[derive(Debug, PartialEq)]
+enum ParseError {
+    Empty,
+    InvalidDigit(char),
+    Overflow,
+}
+
+#[iex]
+fn parse(s: &str) -> Result<u8, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let mut value: u8 = 0;
+    for c in s.chars() {
+        let digit = c.to_digit(10).ok_or(ParseError::InvalidDigit(c))?;
+        value = value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(digit as u8))
+            .ok_or(ParseError::Overflow)?;
+    }
+    Ok(value)
+}
+
+#[iex]
+fn parse_lenient(s: &str) -> Result<u8, ParseError> {
+    let value = match_err!(parse(s), {
+        ParseError::Empty => 0,
+        ParseError::InvalidDigit(c) if c.is_whitespace() => parse(s.trim()).into_result()?,
+    });
+    Ok(value)
+}
+
+#[test]
+fn recovers_matched_errors() {
+    assert_eq!(parse_lenient("12").into_result(), Ok(12));
+    assert_eq!(parse_lenient("").into_result(), Ok(0));
+    assert_eq!(parse_lenient(" 34 ").into_result(), Ok(34));
+}
+
+#[test]
+fn propagates_unmatched_errors() {
+    assert_eq!(
+        parse_lenient("1x").into_result(),
+        Err(ParseError::InvalidDigit('x'))
+    );
+    assert_eq!(
+        parse_lenient("256").into_result(),
+        Err(ParseError::Overflow)
+    );
+}
+
+#[derive(Debug, PartialEq)]
+struct Wrapped(ParseError);
+
+impl From<ParseError> for Wrapped {
+    fn from(err: ParseError) -> Self {
+        Wrapped(err)
+    }
+}
+
+#[iex]
+fn parse_wrapped(s: &str) -> Result<u8, Wrapped> {
+    Ok(match_err!(parse(s), {
+        ParseError::Overflow => u8::MAX,
+    }))
+}
+
+#[test]
+fn converts_propagated_errors() {
+    assert_eq!(parse_wrapped("1000").into_result(), Ok(u8::MAX));
+    assert_eq!(
+        parse_wrapped("").into_result(),
+        Err(Wrapped(ParseError::Empty))
+    );
+}
+
+#[iex]
+fn exhaustive(s: &str) -> Result<u8, ParseError> {
+    Ok(match_err!(parse(s), {
+        ParseError::Empty | ParseError::InvalidDigit(_) => 0,
+        ParseError::Overflow => u8::MAX,
+    }))
+}
+
+#[test]
+fn exhaustive_arms() {
+    assert_eq!(exhaustive("x").into_result(), Ok(0));
+    assert_eq!(exhaustive("999").into_result(), Ok(u8::MAX));
+}
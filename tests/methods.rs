@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use iex::{iex, Outcome};
 
 #[derive(Debug, PartialEq)]
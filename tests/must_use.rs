@@ -0,0 +1,59 @@
+#![cfg(feature = "std")]
+#![deny(unused_attributes)]
+
+use iex::{iex, Outcome};
+use std::process::Command;
+
+#[must_use = "the parsed value must be checked"]
+#[iex]
+fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+    s.parse()
+}
+
+struct Parser;
+
+impl Parser {
+    #[must_use = "the parsed value must be checked"]
+    #[iex]
+    fn parse(&self, s: &str) -> Result<i32, std::num::ParseIntError> {
+        Ok(parse(s)?)
+    }
+}
+
+trait Parse {
+    #[must_use = "the parsed value must be checked"]
+    #[iex]
+    fn parse_trait(&self, s: &str) -> Result<i32, std::num::ParseIntError>;
+}
+
+impl Parse for Parser {
+    #[iex]
+    fn parse_trait(&self, s: &str) -> Result<i32, std::num::ParseIntError> {
+        self.parse(s)
+    }
+}
+
+#[test]
+fn must_use_functions_still_work() {
+    assert_eq!(parse("1").into_result(), Ok(1));
+    assert_eq!(Parser.parse("2").into_result(), Ok(2));
+    assert!(Parser.parse_trait("x").into_result().is_err());
+}
+
+#[test]
+fn custom_message_is_shown() {
+    let target_dir = concat!(env!("CARGO_TARGET_TMPDIR"), "/must_use");
+    let output = Command::new(env!("CARGO"))
+        .args(["rustc", "--quiet", "--example", "must_use", "--target-dir"])
+        .arg(target_dir)
+        .args(["--", "--force-warn", "unused_must_use"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("unused return value of `parse` that must be used"));
+    assert!(stderr.contains("= note: the parsed value must be checked"));
+    assert!(stderr.contains("unused return value of `Parser::parse_port` that must be used"));
+    assert!(stderr.contains("= note: the parsed port must be checked"));
+}
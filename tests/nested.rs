@@ -0,0 +1,83 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+
+#[iex]
+fn check(x: u32) -> Result<u32, String> {
+    if x % 2 == 1 {
+        Err(format!("{x} is odd"))
+    } else {
+        Ok(x)
+    }
+}
+
+#[iex]
+fn nested(inner: u32, outer: u32) -> Result<u32, String> {
+    let recovered = check(inner).into_result().unwrap_or(0);
+    Ok(recovered + check(outer)?)
+}
+
+#[test]
+fn inner_ok_outer_ok() {
+    assert_eq!(nested(2, 4).into_result(), Ok(6));
+}
+
+#[test]
+fn inner_recovered_outer_ok() {
+    assert_eq!(nested(1, 4).into_result(), Ok(4));
+}
+
+#[test]
+fn inner_recovered_outer_err() {
+    assert_eq!(nested(1, 3).into_result(), Err("3 is odd".to_string()));
+}
+
+#[test]
+fn nested_in_error_handler() {
+    let result = check(1)
+        .map_err(|err| {
+            assert_eq!(check(3).into_result(), Err("3 is odd".to_string()));
+            err + "!"
+        })
+        .into_result();
+    assert_eq!(result, Err("1 is odd!".to_string()));
+}
+
+struct ResolvesOnDrop(u32);
+
+impl Drop for ResolvesOnDrop {
+    fn drop(&mut self) {
+        assert_eq!(
+            check(self.0).into_result(),
+            Err(format!("{} is odd", self.0))
+        );
+    }
+}
+
+#[iex]
+fn fails_with_guard() -> Result<u32, String> {
+    let _guard = ResolvesOnDrop(5);
+    // The error is raised while the guard is alive
+    let value = check(7)?;
+    Ok(value)
+}
+
+#[iex]
+fn fails_with_large_error_and_guard() -> Result<(), [String; 4]> {
+    let _guard = ResolvesOnDrop(5);
+    Err::<(), _>(["a", "b", "c", "d"].map(String::from))?;
+    Ok(())
+}
+
+#[test]
+fn nested_during_unwinding() {
+    // The guard resolves a failing outcome while the outer error is being propagated
+    assert_eq!(
+        fails_with_guard().into_result(),
+        Err("7 is odd".to_string())
+    );
+    assert_eq!(
+        fails_with_large_error_and_guard().into_result(),
+        Err(["a", "b", "c", "d"].map(String::from))
+    );
+}
@@ -3,6 +3,8 @@
 //!
 //! It currently dosn't work [but rust 2024 might fix it](https://github.com/iex-rs/iex/issues/1#issuecomment-2307934155)
 
+#![cfg(feature = "std")]
+
 use iex::Outcome;
 
 // #[iex]
@@ -0,0 +1,150 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+
+#[iex]
+fn never_raises(x: u32) -> Result<u32, &'static str> {
+    if x > 10 {
+        return Ok(10);
+    }
+    Ok(x)
+}
+
+#[iex]
+fn panics() -> Result<(), &'static str> {
+    if true {
+        panic!("regular panic");
+    }
+    Ok(())
+}
+
+#[iex]
+fn calls_never_raises() -> Result<u32, String> {
+    Ok(never_raises(5)? + never_raises(20)?)
+}
+
+#[test]
+fn no_raise() {
+    assert_eq!(never_raises(5).into_result(), Ok(5));
+    assert_eq!(never_raises(20).into_result(), Ok(10));
+    assert_eq!(calls_never_raises().into_result(), Ok(15));
+    assert_eq!(
+        never_raises(1).map_err(|e| e.len()).into_result(),
+        Ok::<_, usize>(1)
+    );
+}
+
+#[test]
+fn panic_passes_through() {
+    let payload = std::panic::catch_unwind(|| panics().into_result()).unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"regular panic"));
+}
+
+#[iex]
+fn always_ok(x: u32) -> Result<u32, &'static str> {
+    Ok(x.wrapping_mul(2))
+}
+
+#[test]
+fn always_ok_constant_input() {
+    assert_eq!(always_ok(21).into_result(), Ok(42));
+    assert!(matches!(
+        always_ok(21).into_result_preserving_panic(),
+        Ok(Ok(42))
+    ));
+}
+
+#[iex]
+fn shadowed_ok_fn(x: u32) -> Result<u32, &'static str> {
+    #[allow(non_snake_case)]
+    fn Ok(x: u32) -> Result<u32, &'static str> {
+        if x == 0 {
+            Err("zero")
+        } else {
+            Result::Ok(x)
+        }
+    }
+    Ok(x)
+}
+
+#[test]
+fn shadowed_ok() {
+    assert_eq!(shadowed_ok_fn(1).into_result(), Ok(1));
+    assert_eq!(shadowed_ok_fn(0).into_result(), Err("zero"));
+}
+
+enum Verdict {
+    Ok,
+    Err(&'static str),
+}
+
+#[iex]
+fn shadowed_ok_err_variants(verdict: Verdict) -> Result<u32, &'static str> {
+    use Verdict::{Err, Ok};
+    match verdict {
+        Ok => Result::Ok(1),
+        Err(message) => Result::Err(message),
+    }
+}
+
+#[test]
+fn shadowed_ok_err_variants_resolve() {
+    assert_eq!(shadowed_ok_err_variants(Verdict::Ok).into_result(), Ok(1));
+    assert_eq!(
+        shadowed_ok_err_variants(Verdict::Err("rejected")).into_result(),
+        Err("rejected")
+    );
+}
+
+// `Ok` shadowed outside the function can't be detected by the macro
+mod outer_ok {
+    use iex::{iex, Outcome};
+
+    #[allow(non_snake_case)]
+    fn Ok(x: u32) -> Result<u32, &'static str> {
+        if x == 0 {
+            Err("zero")
+        } else {
+            Result::Ok(x)
+        }
+    }
+
+    #[iex]
+    fn seems_to_never_raise(x: u32) -> Result<u32, &'static str> {
+        Ok(x)
+    }
+
+    #[iex]
+    fn forwards(x: u32) -> Result<u32, &'static str> {
+        Ok(seems_to_never_raise(x)? + 1)
+    }
+
+    #[test]
+    fn resolves_to_err() {
+        assert_eq!(seems_to_never_raise(1).into_result(), Result::Ok(1));
+        assert_eq!(seems_to_never_raise(0).into_result(), Err("zero"));
+        assert!(matches!(
+            seems_to_never_raise(0).into_result_preserving_panic(),
+            Result::Ok(Err("zero"))
+        ));
+        assert_eq!(forwards(1).into_result(), Result::Ok(2));
+        assert_eq!(forwards(0).into_result(), Err("zero"));
+    }
+
+    #[iex]
+    fn succeeds(x: u32) -> Result<u32, &'static str> {
+        Result::Ok(x)
+    }
+
+    #[iex]
+    fn forwarded(x: u32) -> Result<u32, &'static str> {
+        Ok(succeeds(x)?)
+    }
+
+    #[test]
+    fn forwarded_calls_ok() {
+        assert_eq!(forwarded(1).into_result(), Result::Ok(1));
+        // `Ok(0)` is an error here, which forwarding must not skip
+        assert_eq!(forwarded(0).into_result(), Err("zero"));
+    }
+}
@@ -0,0 +1,58 @@
+// Run with `cargo test --no-default-features --test no_std`. The test binary itself uses std, which
+// is only used to implement the unwinding shim.
+#![cfg(not(feature = "std"))]
+
+use iex::{iex, Outcome};
+use std::any::Any;
+
+struct StdUnwinder;
+
+unsafe impl iex::UnwindShim for StdUnwinder {
+    fn catch_unwind(f: &mut dyn FnMut()) -> Result<(), Box<dyn Any + Send>> {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(f))
+    }
+
+    fn resume_unwind(payload: Box<dyn Any + Send>) -> ! {
+        std::panic::resume_unwind(payload)
+    }
+}
+
+iex::unwind_shim!(StdUnwinder);
+
+#[iex]
+fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    s.parse()
+}
+
+#[iex]
+fn sum(values: &[&str]) -> Result<u32, String> {
+    let mut sum = 0;
+    for value in values {
+        sum += parse(value).map_err(|err| format!("{value}: {err}"))?;
+    }
+    Ok(sum)
+}
+
+// The exception slot is a static in no_std mode, so everything is tested on a single thread
+#[test]
+fn no_std() {
+    assert_eq!(sum(&["1", "2", "3"]).into_result(), Ok(6));
+    assert_eq!(
+        sum(&["1", "x"]).into_result(),
+        Err("x: invalid digit found in string".to_string()),
+    );
+
+    let result = sum(&["1", "y"]).into_result_preserving_panic();
+    assert_eq!(
+        result.unwrap(),
+        Err("y: invalid digit found in string".to_string()),
+    );
+
+    let panic = std::panic::catch_unwind(|| {
+        parse("1")
+            .map_err(|_| -> u32 { unreachable!() })
+            .map(|_| -> u32 { panic!("bug") })
+            .into_result()
+    });
+    assert!(panic.is_err());
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, ok_or_continue, Outcome};
+
+#[iex]
+fn check(x: u32) -> Result<u32, String> {
+    if x % 2 == 1 {
+        Err(format!("{x} is odd"))
+    } else {
+        Ok(x)
+    }
+}
+
+#[test]
+fn skips_failing_items() {
+    let mut values = Vec::new();
+    for x in 0..6 {
+        values.push(ok_or_continue!(check(x)));
+    }
+    assert_eq!(values, [0, 2, 4]);
+}
+
+#[test]
+fn plain_results() {
+    let mut values = Vec::new();
+    for s in ["1", "a", "2"] {
+        values.push(ok_or_continue!(s.parse::<u32>()));
+    }
+    assert_eq!(values, [1, 2]);
+}
+
+#[test]
+fn labeled() {
+    let mut rows = Vec::new();
+    'rows: for row in [[0, 2], [4, 5], [6, 8]] {
+        let mut values = Vec::new();
+        for x in row {
+            values.push(ok_or_continue!(check(x), 'rows));
+        }
+        rows.push(values);
+    }
+    assert_eq!(rows, [[0, 2], [6, 8]]);
+}
+
+#[iex]
+fn sum_valid(xs: &[u32]) -> Result<u32, String> {
+    let mut sum = 0;
+    for &x in xs {
+        sum += ok_or_continue!(check(x));
+    }
+    // Errors outside the macro still propagate
+    check(sum)?;
+    Ok(sum)
+}
+
+#[test]
+fn inside_iex() {
+    assert_eq!(sum_valid(&[1, 2, 3, 4]).into_result(), Ok(6));
+}
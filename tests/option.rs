@@ -0,0 +1,108 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, transpose_option, OptionIexExt, Outcome};
+
+#[iex]
+fn decrement(x: u32) -> Result<u32, &'static str> {
+    x.checked_sub(1).ok_or("Underflow")
+}
+
+#[iex]
+fn decrement_if_some(x: Option<u32>) -> Result<Option<u32>, &'static str> {
+    let decremented = transpose_option(x.map(decrement))?;
+    Ok(decremented)
+}
+
+#[test]
+fn transpose_some_ok() {
+    assert_eq!(decrement_if_some(Some(6)).into_result(), Ok(Some(5)));
+}
+
+#[test]
+fn transpose_some_err() {
+    assert_eq!(decrement_if_some(Some(0)).into_result(), Err("Underflow"));
+}
+
+#[test]
+fn transpose_none() {
+    assert_eq!(decrement_if_some(None).into_result(), Ok(None));
+}
+
+#[iex]
+fn decrement_twice_if_some(x: Option<u32>) -> Result<Option<u32>, &'static str> {
+    let decremented = x.map(decrement).transpose_iex()?;
+    decremented.map(decrement).transpose_iex()
+}
+
+#[test]
+fn transpose_iex() {
+    assert_eq!(decrement_twice_if_some(Some(6)).into_result(), Ok(Some(4)));
+    assert_eq!(
+        decrement_twice_if_some(Some(1)).into_result(),
+        Err("Underflow")
+    );
+    assert_eq!(decrement_twice_if_some(None).into_result(), Ok(None));
+}
+
+#[test]
+fn transpose_result() {
+    assert_eq!(
+        transpose_option(Some(Err::<(), _>(1))).into_result(),
+        Err(1)
+    );
+}
+
+#[iex]
+fn find_even(values: &[u32]) -> Result<u32, String> {
+    let value = values
+        .iter()
+        .find(|value| *value % 2 == 0)
+        .ok_or_iex(|| format!("No even number in {values:?}"))?;
+    Ok(*value)
+}
+
+#[test]
+fn ok_or_iex_some() {
+    assert_eq!(find_even(&[1, 4, 6]).into_result(), Ok(4));
+}
+
+#[test]
+fn ok_or_iex_none() {
+    assert_eq!(
+        find_even(&[1, 3]).into_result(),
+        Err("No even number in [1, 3]".to_string())
+    );
+}
+
+#[test]
+fn ok_or_iex_lazy() {
+    let result = Some(1)
+        .ok_or_iex(|| -> &str { unreachable!() })
+        .into_result();
+    assert_eq!(result, Ok(1));
+}
+
+#[derive(Debug, PartialEq)]
+enum LookupError {
+    Missing(u32),
+}
+
+#[iex]
+fn lookup_pair(values: &[u32], a: u32, b: u32) -> Result<u32, LookupError> {
+    let first = values.get(a as usize).ok_or(LookupError::Missing(a))?;
+    let second = values.get(b as usize).ok_or(LookupError::Missing(b))?;
+    Ok(first + second)
+}
+
+#[test]
+fn ok_or_question_mark() {
+    assert_eq!(lookup_pair(&[1, 2], 0, 1).into_result(), Ok(3));
+    assert_eq!(
+        lookup_pair(&[1, 2], 2, 1).into_result(),
+        Err(LookupError::Missing(2))
+    );
+    assert_eq!(
+        lookup_pair(&[1, 2], 0, 5).into_result(),
+        Err(LookupError::Missing(5))
+    );
+}
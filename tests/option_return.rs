@@ -0,0 +1,122 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome, OutcomeOption};
+use std::collections::HashMap;
+
+#[derive(PartialEq, Eq, Hash)]
+struct Key(u32);
+
+struct Table {
+    values: HashMap<Key, String>,
+    links: HashMap<Key, Key>,
+}
+
+impl Table {
+    #[iex]
+    fn lookup(&self, key: &Key) -> Option<&String> {
+        Some(self.values.get(key)?)
+    }
+
+    #[iex]
+    fn follow(&self, key: &Key) -> Option<&String> {
+        let target = self.links.get(key)?;
+        Some(self.lookup(target)?)
+    }
+}
+
+fn table() -> Table {
+    Table {
+        values: HashMap::from([(Key(1), "one".to_string())]),
+        links: HashMap::from([(Key(10), Key(1)), (Key(20), Key(2))]),
+    }
+}
+
+#[test]
+fn lookup() {
+    let table = table();
+    assert_eq!(
+        table.lookup(&Key(1)).into_option(),
+        Some(&"one".to_string())
+    );
+    assert_eq!(table.lookup(&Key(2)).into_option(), None);
+}
+
+#[test]
+fn nested() {
+    let table = table();
+    assert_eq!(
+        table.follow(&Key(10)).into_option(),
+        Some(&"one".to_string())
+    );
+    assert_eq!(table.follow(&Key(20)).into_option(), None);
+    assert_eq!(table.follow(&Key(30)).into_option(), None);
+}
+
+#[iex]
+fn first_even(values: &[u32]) -> Option<u32> {
+    for &value in values {
+        if value % 2 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+#[iex]
+fn sum_first_evens(a: &[u32], b: &[u32]) -> Option<u32> {
+    Some(first_even(a)? + first_even(b)?)
+}
+
+#[test]
+fn returns() {
+    assert_eq!(sum_first_evens(&[1, 2], &[4]).into_option(), Some(6));
+    assert_eq!(sum_first_evens(&[1, 2], &[3]).into_option(), None);
+    assert_eq!(sum_first_evens(&[1], &[4]).into_option(), None);
+}
+
+#[iex]
+fn parse_first(values: &[&str]) -> Result<Option<u32>, std::num::ParseIntError> {
+    // None must not leak into an enclosing function returning Result
+    let first = first_even(&[1]).into_option();
+    match values.first() {
+        Some(value) => Ok(Some(value.parse::<u32>()? + first.unwrap_or(0))),
+        None => Ok(None),
+    }
+}
+
+#[test]
+fn resolved_in_result_fn() {
+    assert_eq!(parse_first(&["5"]).into_result(), Ok(Some(5)));
+    assert!(parse_first(&["x"]).into_result().is_err());
+}
+
+#[iex(also_result = "first_even_option")]
+fn first_even_or_none(values: &[u32]) -> std::option::Option<u32> {
+    Some(first_even(values)?)
+}
+
+#[test]
+fn also_result() {
+    assert_eq!(first_even_or_none(&[3, 8]).into_option(), Some(8));
+    assert_eq!(first_even_option(&[3]), None);
+}
+
+trait Store {
+    #[iex]
+    fn get(&self, key: u32) -> Option<u32>;
+}
+
+struct Doubles;
+
+impl Store for Doubles {
+    #[iex]
+    fn get(&self, key: u32) -> Option<u32> {
+        Some(key.checked_mul(2)?)
+    }
+}
+
+#[test]
+fn trait_method() {
+    assert_eq!(Doubles.get(2).into_option(), Some(4));
+    assert_eq!(Doubles.get(u32::MAX).into_option(), None);
+}
@@ -0,0 +1,88 @@
+#![cfg(all(feature = "std", not(feature = "plain")))]
+
+use iex::{iex, Outcome};
+use std::cell::RefCell;
+
+thread_local! {
+    static EVENTS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn log(event: String) {
+    EVENTS.with_borrow_mut(|events| events.push(event));
+}
+
+fn take_events() -> Vec<String> {
+    EVENTS.take()
+}
+
+struct LogOnDrop;
+
+impl Drop for LogOnDrop {
+    fn drop(&mut self) {
+        log("dropped".to_string());
+    }
+}
+
+#[iex]
+fn buggy(fail: bool) -> Result<(), String> {
+    if fail {
+        Err("Error".to_string())
+    } else {
+        panic!("Bug")
+    }
+}
+
+fn bug() {
+    panic!("Bug")
+}
+
+// Never raises an error, so it's resolved without catch_unwind
+#[iex]
+fn buggy_no_raise() -> Result<(), String> {
+    bug();
+    Ok(())
+}
+
+fn resolve_outcome(
+    outcome: impl FnOnce() -> Result<(), String> + std::panic::UnwindSafe,
+) -> Result<Result<(), String>, String> {
+    std::panic::catch_unwind(|| {
+        let _guard = LogOnDrop;
+        outcome()
+    })
+    .map_err(|payload| *payload.downcast::<&str>().unwrap())
+    .map_err(str::to_string)
+}
+
+fn resolve(fail: bool) -> Result<Result<(), String>, String> {
+    resolve_outcome(move || buggy(fail).into_result())
+}
+
+// The hook is global, so everything is tested sequentially in a single test
+#[test]
+fn foreign_panic_hook() {
+    iex::set_foreign_panic_hook(Box::new(|payload| {
+        log(format!("hook: {}", payload.downcast_ref::<&str>().unwrap()));
+    }));
+
+    // The hook fires before the panic continues unwinding, and the payload is preserved
+    assert_eq!(resolve(false), Err("Bug".to_string()));
+    assert_eq!(take_events(), ["hook: Bug", "dropped"]);
+
+    // Functions that never raise errors are resolved without catching panics, so the hook doesn't
+    // observe them
+    assert_eq!(
+        resolve_outcome(|| buggy_no_raise().into_result()),
+        Err("Bug".to_string())
+    );
+    assert_eq!(take_events(), ["dropped"]);
+
+    // Errors don't trigger the hook
+    assert_eq!(resolve(true), Ok(Err("Error".to_string())));
+    assert_eq!(take_events(), ["dropped"]);
+
+    // A panicking hook doesn't replace the original panic
+    iex::set_foreign_panic_hook(Box::new(|_| panic!("Hook")));
+    assert_eq!(resolve(false), Err("Bug".to_string()));
+    assert_eq!(take_events(), ["dropped"]);
+}
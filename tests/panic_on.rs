@@ -0,0 +1,69 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[derive(Debug, PartialEq)]
+enum StoreError {
+    NotFound,
+    Corrupted,
+}
+
+#[iex]
+fn load(key: &str) -> Result<u32, StoreError> {
+    match key {
+        "missing" => Err(StoreError::NotFound),
+        "broken" => Err(StoreError::Corrupted),
+        _ => Ok(1),
+    }
+}
+
+#[iex]
+fn load_checked(key: &str) -> Result<u32, String> {
+    let value = load(key)
+        .panic_on(|err| *err == StoreError::Corrupted)
+        .map_err(|err| format!("{err:?}"))?;
+    Ok(value + 1)
+}
+
+#[test]
+fn ok_passes_through() {
+    assert_eq!(load_checked("present").into_result(), Ok(2));
+}
+
+#[test]
+fn unmatched_error_propagates() {
+    assert_eq!(
+        load_checked("missing").into_result(),
+        Err("NotFound".to_string())
+    );
+}
+
+#[test]
+fn matched_error_panics() {
+    let panic = catch_unwind(AssertUnwindSafe(|| load_checked("broken").into_result()));
+    assert!(panic.is_err());
+
+    // The error is only recoverable from the panic when it's propagated by unwinding
+    #[cfg(not(feature = "plain"))]
+    {
+        let err = load("broken")
+            .panic_on(|err| *err == StoreError::Corrupted)
+            .into_result_preserving_panic()
+            .unwrap_err();
+        assert_eq!(err.original, Some(StoreError::Corrupted));
+    }
+}
+
+#[test]
+fn pred_is_lazy() {
+    let mut calls = 0;
+    let result = load("present")
+        .panic_on(|_| {
+            calls += 1;
+            true
+        })
+        .into_result();
+    assert_eq!(result, Ok(1));
+    assert_eq!(calls, 0);
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+
+#[iex]
+fn checked_sum((a, b): (u32, u32)) -> Result<u32, &'static str> {
+    a.checked_add(b).ok_or("Overflow")
+}
+
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[iex]
+fn area(Point { x, y }: Point) -> Result<u32, &'static str> {
+    x.checked_mul(y).ok_or("Overflow")
+}
+
+#[iex]
+fn first(&(a, _): &(u32, u32), [b, ..]: [u32; 3]) -> Result<u32, &'static str> {
+    Ok(a + b)
+}
+
+#[iex]
+fn nested(
+    Point { x, y: _ }: Point,
+    (Point { x: x2, .. }, _): (Point, ()),
+) -> Result<u32, &'static str> {
+    Ok(checked_sum((x, x2))?)
+}
+
+#[test]
+fn tuple_destructuring() {
+    assert_eq!(checked_sum((1, 2)).into_result(), Ok(3));
+    assert_eq!(checked_sum((u32::MAX, 1)).into_result(), Err("Overflow"));
+}
+
+#[test]
+fn struct_destructuring() {
+    assert_eq!(area(Point { x: 3, y: 4 }).into_result(), Ok(12));
+    assert_eq!(
+        area(Point { x: u32::MAX, y: 2 }).into_result(),
+        Err("Overflow"),
+    );
+}
+
+#[test]
+fn ref_and_slice_destructuring() {
+    assert_eq!(first(&(1, 2), [3, 4, 5]).into_result(), Ok(4));
+}
+
+#[test]
+fn nested_destructuring() {
+    assert_eq!(
+        nested(Point { x: 1, y: 2 }, (Point { x: 3, y: 4 }, ())).into_result(),
+        Ok(4),
+    );
+}
+
+impl Point {
+    #[iex]
+    fn offset(&self, (dx, dy): (u32, u32)) -> Result<Point, &'static str> {
+        Ok(Point {
+            x: checked_sum((self.x, dx))?,
+            y: checked_sum((self.y, dy))?,
+        })
+    }
+}
+
+trait Scale {
+    #[iex]
+    fn scale(&self, factors: (u32, u32)) -> Result<u32, &'static str>;
+}
+
+impl Scale for Point {
+    #[iex]
+    fn scale(&self, (mut fx, fy): (u32, u32)) -> Result<u32, &'static str> {
+        fx = fx.checked_mul(self.x).ok_or("Overflow")?;
+        Ok(checked_sum((fx, fy * self.y))?)
+    }
+}
+
+#[test]
+fn method_destructuring() {
+    let point = Point { x: 1, y: 2 }.offset((3, 4)).into_result().unwrap();
+    assert_eq!((point.x, point.y), (4, 6));
+    assert!(point.offset((u32::MAX, 0)).into_result().is_err());
+    assert_eq!(point.scale((2, 3)).into_result(), Ok(26));
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use iex::{iex, Outcome};
 
 fn result_divide(a: u32, b: u32) -> Result<u32, &'static str> {
@@ -32,3 +34,114 @@ fn simple_propagation() {
         "Cannot divide by zero",
     );
 }
+
+#[iex]
+fn parse_doubled(s: &str) -> Result<u32, String> {
+    s.parse::<u32>()
+        .map(|x| x * 2)
+        .map_err(|err| err.to_string())
+}
+
+#[iex]
+fn divide_tail(a: u32, b: u32) -> Result<u32, String> {
+    checked_divide(a, b).map_err(|err| err.to_string())
+}
+
+#[test]
+fn result_tail_expression() {
+    assert_eq!(parse_doubled("21").into_result(), Ok(42));
+    assert_eq!(
+        parse_doubled("abc").into_result(),
+        Err("invalid digit found in string".to_string()),
+    );
+    assert_eq!(divide_tail(6, 3).into_result(), Ok(2));
+    assert_eq!(
+        divide_tail(6, 0).into_result(),
+        Err("Cannot divide by zero".to_string()),
+    );
+}
+
+#[iex]
+fn resolve_and_raise(a: u32, b: u32) -> Result<u32, String> {
+    Ok(checked_divide(a, b).into_result()? + result_divide(a, b).into_result()?)
+}
+
+#[iex]
+fn resolve_generic<R: Outcome<Output = u32, Error = &'static str>>(
+    outcome: R,
+) -> Result<u32, &'static str> {
+    Ok(outcome.into_result()?)
+}
+
+struct Status(i32);
+
+impl Status {
+    fn into_result(self) -> Result<(), String> {
+        if self.0 == 0 {
+            Ok(())
+        } else {
+            Err(format!("status {}", self.0))
+        }
+    }
+}
+
+#[iex]
+fn check_status(status: i32) -> Result<(), String> {
+    Status(status).into_result()?;
+    Ok(())
+}
+
+#[test]
+fn into_result_then_try() {
+    assert_eq!(resolve_and_raise(6, 3).into_result(), Ok(4));
+    assert_eq!(
+        resolve_and_raise(6, 0).into_result(),
+        Err("Cannot divide by zero".to_string()),
+    );
+    assert_eq!(resolve_generic(checked_divide(6, 2)).into_result(), Ok(3));
+    assert_eq!(
+        resolve_generic(checked_divide(6, 0)).into_result(),
+        Err("Cannot divide by zero"),
+    );
+    assert_eq!(check_status(0).into_result(), Ok(()));
+    assert_eq!(check_status(2).into_result(), Err("status 2".to_string()),);
+}
+
+// `Outcome` is not in scope, so `.into_result()` refers to the extension trait even on outcomes
+mod extension_trait {
+    use iex::iex;
+
+    trait IntoResultExt<T> {
+        fn into_result(self) -> Result<T, String>;
+    }
+
+    impl<R: iex::Outcome<Error = &'static str>> IntoResultExt<R::Output> for R {
+        fn into_result(self) -> Result<R::Output, String> {
+            iex::Outcome::into_result(self).map_err(|err| format!("extension: {err}"))
+        }
+    }
+
+    #[iex]
+    pub fn divide_result(a: u32, b: u32) -> Result<u32, String> {
+        Ok(super::result_divide(a, b).into_result()?)
+    }
+
+    #[iex]
+    pub fn divide_outcome(a: u32, b: u32) -> Result<u32, String> {
+        Ok(super::checked_divide(a, b).into_result()?)
+    }
+}
+
+#[test]
+fn into_result_extension_trait() {
+    assert_eq!(extension_trait::divide_result(6, 3).into_result(), Ok(2));
+    assert_eq!(
+        extension_trait::divide_result(6, 0).into_result(),
+        Err("extension: Cannot divide by zero".to_string()),
+    );
+    assert_eq!(extension_trait::divide_outcome(6, 3).into_result(), Ok(2));
+    assert_eq!(
+        extension_trait::divide_outcome(6, 0).into_result(),
+        Err("extension: Cannot divide by zero".to_string()),
+    );
+}
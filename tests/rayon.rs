@@ -0,0 +1,50 @@
+#![cfg(feature = "rayon")]
+
+use iex::{iex, par_map_collect, Outcome};
+
+#[iex]
+fn check(x: u32) -> Result<u32, String> {
+    if x == 500 {
+        Err(format!("{x} is forbidden"))
+    } else {
+        Ok(x * 2)
+    }
+}
+
+#[test]
+fn par_map_collect_ok() {
+    assert_eq!(
+        par_map_collect(0..100, check),
+        Ok((0..100).map(|x| x * 2).collect())
+    );
+}
+
+#[test]
+fn par_map_collect_err() {
+    assert_eq!(
+        par_map_collect(0..1000, check),
+        Err("500 is forbidden".to_string())
+    );
+}
+
+#[test]
+fn par_map_collect_result_closure() {
+    let words = vec!["1", "2", "x"];
+    let parse = |s: &&str| s.parse::<u32>().map_err(|err| err.to_string());
+    assert_eq!(par_map_collect(&words[..2], parse), Ok(vec![1, 2]));
+    assert!(par_map_collect(&words, parse).is_err());
+}
+
+#[iex]
+fn sum_doubled(values: &[u32]) -> Result<u32, String> {
+    Ok(par_map_collect(values, |&x| check(x))?.iter().sum())
+}
+
+#[test]
+fn par_map_collect_propagate() {
+    assert_eq!(sum_doubled(&[1, 2, 3]).into_result(), Ok(12));
+    assert_eq!(
+        sum_doubled(&[1, 500, 3]).into_result(),
+        Err("500 is forbidden".to_string())
+    );
+}
@@ -0,0 +1,54 @@
+#![cfg(all(feature = "realtime", debug_assertions, not(feature = "plain")))]
+
+use iex::{iex, CountingAllocator, Outcome};
+use std::alloc::System;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator<System> = CountingAllocator(System);
+
+#[iex]
+fn fails_with_code() -> Result<(), u32> {
+    Err(123)
+}
+
+#[allow(clippy::result_large_err)]
+#[iex]
+fn fails_with_large_error() -> Result<(), [u64; 16]> {
+    Err([1; 16])
+}
+
+#[test]
+fn allocation_free_error() {
+    assert_eq!(fails_with_code().into_result(), Err(123));
+    assert_eq!(
+        fails_with_code().map_err(|code| code + 1).into_result(),
+        Err(124),
+    );
+}
+
+#[test]
+#[should_panic(expected = "allocation(s) were performed on the error path")]
+fn allocating_map_err() {
+    let _ = fails_with_code()
+        .map_err(|code| code.to_string())
+        .into_result();
+}
+
+#[test]
+#[should_panic(expected = "allocation(s) were performed on the error path")]
+fn large_error() {
+    let _ = fails_with_large_error().into_result();
+}
+
+#[test]
+fn allocation_before_raise_is_ignored() {
+    #[iex]
+    fn fails_with_message() -> Result<(), String> {
+        Err("Failed".to_string())
+    }
+
+    assert_eq!(
+        fails_with_message().into_result(),
+        Err("Failed".to_string()),
+    );
+}
@@ -0,0 +1,230 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+
+#[derive(Debug, PartialEq)]
+enum NetError {
+    Timeout,
+    Refused,
+}
+
+#[iex]
+fn connect(attempt: u32, fail_with: &[NetError]) -> Result<u32, NetError> {
+    match fail_with.get(attempt as usize) {
+        Some(NetError::Timeout) => Err(NetError::Timeout),
+        Some(NetError::Refused) => Err(NetError::Refused),
+        None => Ok(attempt),
+    }
+}
+
+fn connect_with_retries(fail_with: &[NetError]) -> (Result<u32, NetError>, u32) {
+    let mut retries = 0;
+    loop {
+        let (result, retryable) =
+            connect(retries, fail_with).resolve_classified(|err| *err == NetError::Timeout);
+        if retryable == Some(true) {
+            retries += 1;
+        } else {
+            return (result, retries);
+        }
+    }
+}
+
+#[test]
+fn classified_retry() {
+    assert_eq!(connect_with_retries(&[]), (Ok(0), 0));
+    assert_eq!(
+        connect_with_retries(&[NetError::Timeout, NetError::Timeout]),
+        (Ok(2), 2)
+    );
+    assert_eq!(
+        connect_with_retries(&[NetError::Timeout, NetError::Refused]),
+        (Err(NetError::Refused), 1)
+    );
+}
+
+#[test]
+fn classified_ok_is_unclassified() {
+    let mut called = false;
+    let (result, class) = Ok::<_, NetError>(1).resolve_classified(|_| {
+        called = true;
+        true
+    });
+    assert_eq!(result, Ok(1));
+    assert_eq!(class, None);
+    assert!(!called);
+}
+
+#[test]
+fn resolve_to_ok() {
+    let result = connect(0, &[]).resolve_to(|attempt| attempt + 10, |_| unreachable!());
+    assert_eq!(result, 10);
+}
+
+#[test]
+fn resolve_to_err() {
+    let result = connect(0, &[NetError::Refused]).resolve_to(
+        |_| unreachable!(),
+        |err| match err {
+            NetError::Timeout => "timeout",
+            NetError::Refused => "refused",
+        },
+    );
+    assert_eq!(result, "refused");
+}
+
+#[test]
+fn resolve_to_consumes_error() {
+    use std::rc::Rc;
+
+    #[iex]
+    fn fails(token: Rc<()>) -> Result<(), Rc<()>> {
+        Err(token)
+    }
+
+    let token = Rc::new(());
+    let returned = fails(token.clone()).resolve_to(|()| None, Some).unwrap();
+    assert_eq!(Rc::strong_count(&token), 2);
+    drop(returned);
+    assert_eq!(Rc::strong_count(&token), 1);
+}
+
+impl std::fmt::Display for NetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetError::Timeout => write!(f, "connection timed out"),
+            NetError::Refused => write!(f, "connection refused"),
+        }
+    }
+}
+
+#[test]
+fn resolve_err_string() {
+    assert_eq!(connect(1, &[]).resolve_err_string(), Ok(1));
+    assert_eq!(
+        connect(0, &[NetError::Timeout]).resolve_err_string(),
+        Err("connection timed out".to_string())
+    );
+    assert_eq!(
+        Err::<(), _>(NetError::Refused).resolve_err_string(),
+        Err("connection refused".to_string())
+    );
+}
+
+#[test]
+fn unwrap_or() {
+    assert_eq!(connect(2, &[]).unwrap_or(8080), 2);
+    assert_eq!(connect(0, &[NetError::Refused]).unwrap_or(8080), 8080);
+}
+
+#[test]
+fn unwrap_or_else() {
+    let mut seen = None;
+    assert_eq!(
+        connect(0, &[NetError::Timeout]).unwrap_or_else(|err| {
+            seen = Some(err);
+            7
+        }),
+        7
+    );
+    assert_eq!(seen, Some(NetError::Timeout));
+    assert_eq!(connect(3, &[]).unwrap_or_else(|_| unreachable!()), 3);
+}
+
+#[test]
+fn map_or() {
+    let mut calls = 0;
+    let mut double = |attempt| {
+        calls += 1;
+        attempt * 2
+    };
+    assert_eq!(connect(2, &[]).map_or(0, &mut double), 4);
+    assert_eq!(connect(0, &[NetError::Refused]).map_or(0, &mut double), 0);
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn map_or_else() {
+    let describe = |fail_with: &[NetError]| {
+        connect(0, fail_with).map_or_else(|err| format!("{err}"), |attempt| format!("#{attempt}"))
+    };
+    assert_eq!(describe(&[]), "#0");
+    assert_eq!(describe(&[NetError::Timeout]), "connection timed out");
+    assert_eq!(
+        connect(1, &[]).map_or_else(|_| unreachable!(), |attempt| attempt),
+        1
+    );
+}
+
+#[iex]
+fn connect_or_default(fail_with: &[NetError]) -> Result<u32, NetError> {
+    // An error resolved by unwrap_or must not affect the enclosing function
+    let attempt = connect(0, fail_with).unwrap_or(100);
+    Ok(attempt + connect(1, &[])?)
+}
+
+#[test]
+fn unwrap_or_in_iex() {
+    assert_eq!(
+        connect_or_default(&[NetError::Refused]).into_result(),
+        Ok(101)
+    );
+    assert_eq!(connect_or_default(&[]).into_result(), Ok(1));
+}
+
+#[test]
+fn ok_and_err() {
+    assert_eq!(connect(2, &[]).ok(), Some(2));
+    assert_eq!(connect(0, &[NetError::Refused]).ok(), None);
+    assert_eq!(
+        connect(0, &[NetError::Timeout]).err(),
+        Some(NetError::Timeout)
+    );
+    assert_eq!(connect(2, &[]).err(), None);
+}
+
+#[iex]
+fn count_connection(count: &mut u32) -> Result<u32, NetError> {
+    *count += 1;
+    connect(1, &[])
+}
+
+#[test]
+fn err_runs_successful_outcome() {
+    let mut count = 0;
+    assert_eq!(count_connection(&mut count).err(), None);
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn resolve_split() {
+    assert_eq!(connect(2, &[]).resolve_split(), (Some(2), None));
+    assert_eq!(
+        connect(0, &[NetError::Refused]).resolve_split(),
+        (None, Some(NetError::Refused))
+    );
+}
+
+#[cfg(not(feature = "plain"))]
+#[iex]
+fn slow_connection(fail_with: &[NetError]) -> Result<u32, NetError> {
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    connect(0, fail_with)
+}
+
+#[cfg(not(feature = "plain"))]
+#[test]
+fn resolve_with_hook_timing() {
+    for fail_with in [&[][..], &[NetError::Timeout]] {
+        let start = std::cell::Cell::new(None);
+        let (result, elapsed) = slow_connection(fail_with).resolve_with_hook(
+            || start.set(Some(std::time::Instant::now())),
+            |result| {
+                assert_eq!(result.is_err(), !fail_with.is_empty());
+                start.get().unwrap().elapsed()
+            },
+        );
+        assert_eq!(result.is_err(), !fail_with.is_empty());
+        assert!(elapsed >= std::time::Duration::from_millis(20));
+    }
+}
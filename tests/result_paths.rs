@@ -0,0 +1,85 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+
+#[iex]
+fn core_result(x: i32) -> core::result::Result<i32, String> {
+    if x < 0 {
+        Err(format!("{x} is negative"))
+    } else {
+        Ok(x)
+    }
+}
+
+#[iex]
+fn std_result(x: i32) -> std::result::Result<i32, String> {
+    Ok(core_result(x)? + 1)
+}
+
+type LocalResult<T> = Result<T, String>;
+
+#[iex]
+fn local_alias(x: i32) -> LocalResult<i32> {
+    Ok(std_result(x)? * 2)
+}
+
+mod errors {
+    pub type Result<T> = std::result::Result<T, String>;
+}
+
+#[iex]
+fn reexported_alias(x: i32) -> errors::Result<i32> {
+    local_alias(x)
+}
+
+#[test]
+fn result_paths() {
+    assert_eq!(reexported_alias(1).into_result(), Ok(4));
+    assert_eq!(
+        reexported_alias(-1).into_result(),
+        Err("-1 is negative".to_string())
+    );
+}
+
+struct Checker;
+
+impl Checker {
+    #[iex]
+    fn check(&self, x: i32) -> errors::Result<i32> {
+        Ok(core_result(x)?)
+    }
+}
+
+#[test]
+fn result_paths_method() {
+    assert_eq!(Checker.check(1).into_result(), Ok(1));
+    assert!(Checker.check(-1).into_result().is_err());
+}
+
+#[cfg(feature = "anyhow")]
+#[iex]
+fn anyhow_result(x: i32) -> anyhow::Result<i32> {
+    if x < 0 {
+        anyhow::bail!("{x} is negative");
+    }
+    Ok(x)
+}
+
+#[cfg(feature = "anyhow")]
+#[iex]
+fn calls_anyhow_result(x: i32) -> anyhow::Result<i32> {
+    Ok(anyhow_result(x)? * 2)
+}
+
+#[cfg(feature = "anyhow")]
+#[test]
+fn anyhow_result_path() {
+    assert_eq!(calls_anyhow_result(1).into_result().unwrap(), 2);
+    assert_eq!(
+        calls_anyhow_result(-1)
+            .into_result()
+            .unwrap_err()
+            .to_string(),
+        "-1 is negative"
+    );
+}
@@ -0,0 +1,110 @@
+// The JSON output format is unstable
+#![cfg(all(feature = "std", feature = "nightly"))]
+
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::process::Command;
+
+fn rustdoc_json() -> Value {
+    let target_dir = concat!(env!("CARGO_TARGET_TMPDIR"), "/rustdoc");
+    let output = Command::new(env!("CARGO"))
+        .args([
+            "rustdoc",
+            "--quiet",
+            "--example",
+            "doc_shapes",
+            "--target-dir",
+        ])
+        .arg(target_dir)
+        .args(["--", "-Zunstable-options", "--output-format", "json"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json = std::fs::read(format!("{target_dir}/doc/doc_shapes.json")).unwrap();
+    serde_json::from_slice(&json).unwrap()
+}
+
+// IDs differ between the modules even for the same types, e.g. `Self`
+fn strip_ids(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .filter(|(key, _)| *key != "id")
+                .map(|(key, value)| (key.clone(), strip_ids(value)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.iter().map(strip_ids).collect()),
+        _ => value.clone(),
+    }
+}
+
+// Collects free functions, inherent methods and trait methods by name
+fn functions(index: &Value, module: &Value) -> BTreeMap<String, Value> {
+    let item = |id: &Value| &index[id.to_string()];
+    let mut functions = BTreeMap::new();
+    let mut add = |prefix: &str, function: &Value| {
+        let name = function["name"].as_str().unwrap();
+        functions.insert(
+            format!("{prefix}{name}"),
+            strip_ids(&function["inner"]["function"]),
+        );
+    };
+    for id in module["inner"]["module"]["items"].as_array().unwrap() {
+        let child = item(id);
+        let name = child["name"].as_str().unwrap();
+        let inner = &child["inner"];
+        if inner.get("function").is_some() {
+            add("", child);
+        } else if let Some(structure) = inner.get("struct") {
+            for impl_id in structure["impls"].as_array().unwrap() {
+                let imp = &item(impl_id)["inner"]["impl"];
+                if imp["trait"].is_null() {
+                    for method in imp["items"].as_array().unwrap() {
+                        add(&format!("{name}::"), item(method));
+                    }
+                }
+            }
+        } else if let Some(tr) = inner.get("trait") {
+            for method in tr["items"].as_array().unwrap() {
+                add(&format!("{name}::"), item(method));
+            }
+        }
+    }
+    functions
+}
+
+#[test]
+fn documented_signatures() {
+    let json = rustdoc_json();
+    let index = &json["index"];
+    let root = &index[json["root"].to_string()];
+    let module = |name: &str| {
+        root["inner"]["module"]["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|id| &index[id.to_string()])
+            .find(|item| item["name"] == name)
+            .unwrap()
+    };
+
+    let iex_functions = functions(index, module("iex_shapes"));
+    let plain_functions = functions(index, module("plain_shapes"));
+    assert_eq!(
+        iex_functions.keys().collect::<Vec<_>>(),
+        plain_functions.keys().collect::<Vec<_>>(),
+    );
+    assert!(iex_functions.contains_key("asynchronous"));
+    assert!(iex_functions.contains_key("Trait::required"));
+    assert!(iex_functions.contains_key("Trait::boxed"));
+    // #[doc(hidden)] is preserved
+    assert!(!iex_functions.contains_key("hidden"));
+    for (name, function) in &iex_functions {
+        assert_eq!(function, &plain_functions[name], "signature of {name}");
+    }
+}
@@ -0,0 +1,106 @@
+#![cfg(all(feature = "std", not(feature = "plain")))]
+
+use iex::{iex, Outcome};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[iex]
+fn fails(x: i32) -> Result<i32, i32> {
+    Err(x)
+}
+
+#[iex]
+fn maps_err_with_panic() -> Result<i32, String> {
+    let value = fails(1).map_err(|_| -> String { panic!("map_err panicked") })?;
+    Ok(value)
+}
+
+#[iex]
+fn inspects_err_with_panic() -> Result<i32, String> {
+    let value = fails(2)
+        .inspect_err(|_| panic!("inspect_err panicked"))
+        .map_err(|err| err.to_string())?;
+    Ok(value)
+}
+
+#[test]
+fn map_err_panic_is_reported() {
+    let err = maps_err_with_panic()
+        .into_result_preserving_panic()
+        .unwrap_err();
+    assert!(err.original.is_none());
+    assert_eq!(err.panic.downcast_ref::<&str>(), Some(&"map_err panicked"));
+}
+
+#[test]
+fn inspect_err_panic_keeps_error() {
+    let err = inspects_err_with_panic()
+        .into_result_preserving_panic()
+        .unwrap_err();
+    assert_eq!(err.original.as_deref(), Some("2"));
+    assert_eq!(
+        err.panic.downcast_ref::<&str>(),
+        Some(&"inspect_err panicked")
+    );
+}
+
+#[test]
+fn into_result_resumes_panic() {
+    let payload =
+        catch_unwind(AssertUnwindSafe(|| maps_err_with_panic().into_result())).unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"map_err panicked"));
+
+    // Error propagation still works afterwards
+    assert_eq!(fails(3).into_result(), Err(3));
+    assert_eq!(fails(4).into_result_preserving_panic().unwrap(), Err(4));
+}
+
+#[test]
+fn no_secondary_panic() {
+    assert_eq!(
+        fails(5)
+            .map_err(|err| err + 1)
+            .into_result_preserving_panic()
+            .unwrap(),
+        Err(6)
+    );
+    assert_eq!(
+        Ok::<_, ()>(1).into_result_preserving_panic().unwrap(),
+        Ok(1)
+    );
+}
+
+#[test]
+fn result_inspect_err_panic_keeps_error() {
+    // Result::inspect_err is an inherent method, so call the Outcome one explicitly
+    let err = Outcome::inspect_err(Err::<i32, i32>(7), |_| panic!("inspect_err panicked"))
+        .into_result_preserving_panic()
+        .unwrap_err();
+    assert_eq!(err.original, Some(7));
+    assert_eq!(
+        err.panic.downcast_ref::<&str>(),
+        Some(&"inspect_err panicked")
+    );
+}
+
+struct ResolvesOnDrop;
+
+impl Drop for ResolvesOnDrop {
+    fn drop(&mut self) {
+        assert_eq!(fails(8).into_result(), Err(8));
+    }
+}
+
+#[iex]
+fn resolves_while_panic_is_pending() -> Result<i32, String> {
+    let _guard = ResolvesOnDrop;
+    Ok(maps_err_with_panic()?)
+}
+
+#[test]
+fn pending_panic_is_not_taken_by_nested_resolve() {
+    let err = resolves_while_panic_is_pending()
+        .into_result_preserving_panic()
+        .unwrap_err();
+    assert!(err.original.is_none());
+    assert_eq!(err.panic.downcast_ref::<&str>(), Some(&"map_err panicked"));
+}
@@ -0,0 +1,102 @@
+// An `#[iex] Result` stores the arguments of the call and nothing else, so its size must not depend
+// on the output or error types.
+
+// Measuring an outcome drops it unresolved, which the `check-unresolved` feature reports
+#![cfg(all(
+    feature = "std",
+    not(any(feature = "plain", feature = "check-unresolved"))
+))]
+
+use iex::{iex, Outcome};
+use std::mem::size_of_val;
+
+#[iex]
+fn no_args() -> Result<u32, u32> {
+    Ok(1)
+}
+
+#[iex]
+fn one_u32(x: u32) -> Result<u32, u32> {
+    if x > 0 {
+        Ok(x)
+    } else {
+        Err(x)
+    }
+}
+
+#[iex]
+fn two_u64(x: u64, y: u64) -> Result<u64, String> {
+    x.checked_add(y).ok_or_else(|| "Overflow".to_string())
+}
+
+#[iex]
+fn borrows(s: &str) -> Result<usize, std::num::ParseIntError> {
+    s.parse()
+}
+
+#[allow(clippy::result_large_err)]
+#[iex]
+fn large_err(x: u8) -> Result<u8, [u8; 1024]> {
+    if x > 0 {
+        Ok(x)
+    } else {
+        Err([0; 1024])
+    }
+}
+
+#[iex]
+fn large_ok(x: u8) -> Result<[u8; 1024], u8> {
+    if x > 0 {
+        Ok([x; 1024])
+    } else {
+        Err(x)
+    }
+}
+
+#[iex]
+fn calls(x: u32) -> Result<u32, u32> {
+    Ok(one_u32(x)? + one_u32(x + 1)?)
+}
+
+struct Parser {
+    radix: u32,
+}
+
+impl Parser {
+    #[iex]
+    fn parse(&self, s: &str) -> Result<u32, std::num::ParseIntError> {
+        u32::from_str_radix(s, self.radix)
+    }
+}
+
+#[test]
+fn size_is_size_of_arguments() {
+    assert_eq!(size_of_val(&no_args()), 0);
+    assert_eq!(size_of_val(&one_u32(1)), 4);
+    assert_eq!(size_of_val(&two_u64(1, 2)), 16);
+    assert_eq!(size_of_val(&borrows("1")), size_of::<&str>());
+    assert_eq!(size_of_val(&calls(1)), 4);
+    let parser = Parser { radix: 10 };
+    assert_eq!(
+        size_of_val(&parser.parse("1")),
+        size_of::<&Parser>() + size_of::<&str>()
+    );
+}
+
+#[test]
+fn size_is_independent_of_result_types() {
+    assert_eq!(size_of_val(&large_err(1)), 1);
+    assert_eq!(size_of_val(&large_ok(1)), 1);
+}
+
+#[test]
+fn combinators_add_closure_captures() {
+    assert_eq!(size_of_val(&one_u32(1).map_err(|e| e as u64)), 4);
+    assert_eq!(size_of_val(&one_u32(1).map(|x| x as u64)), 4);
+    let offset = 1u32;
+    assert_eq!(size_of_val(&one_u32(1).map(move |x| x + offset)), 8);
+    assert_eq!(
+        size_of_val(&one_u32(1).boxed()),
+        size_of::<Box<dyn FnOnce()>>()
+    );
+}
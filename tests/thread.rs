@@ -0,0 +1,76 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, send_results, spawn_scoped_resolve, Outcome};
+
+#[iex]
+fn parse_all(strs: &[String]) -> Result<Vec<i32>, String> {
+    let mut values = Vec::new();
+    for s in strs {
+        values.push(s.parse().map_err(|_| format!("Invalid number: {s}"))?);
+    }
+    Ok(values)
+}
+
+#[test]
+fn scoped() {
+    let good = vec!["1".to_string(), "2".to_string()];
+    let bad = vec!["3".to_string(), "x".to_string()];
+    std::thread::scope(|s| {
+        let good_handle = spawn_scoped_resolve(s, || parse_all(&good));
+        let bad_handle = spawn_scoped_resolve(s, || parse_all(&bad));
+        assert_eq!(good_handle.join().unwrap(), Ok(vec![1, 2]));
+        assert_eq!(
+            bad_handle.join().unwrap(),
+            Err("Invalid number: x".to_string())
+        );
+    });
+    // The spawning thread's state is unaffected
+    assert_eq!(
+        parse_all(&bad).into_result(),
+        Err("Invalid number: x".to_string())
+    );
+}
+
+#[iex]
+fn check(x: u32) -> Result<u32, String> {
+    if x % 2 == 1 {
+        Err(format!("{x} is odd"))
+    } else {
+        Ok(x)
+    }
+}
+
+#[test]
+fn send_results_in_order() {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let producer = std::thread::spawn(move || send_results((0..5).map(check), &tx));
+    let results: Vec<_> = rx.iter().collect();
+    assert_eq!(
+        results,
+        [
+            Ok(0),
+            Err("1 is odd".to_string()),
+            Ok(2),
+            Err("3 is odd".to_string()),
+            Ok(4),
+        ]
+    );
+    assert!(producer.join().unwrap().is_ok());
+}
+
+#[test]
+fn send_results_disconnected() {
+    let (tx, rx) = std::sync::mpsc::channel();
+    drop(rx);
+    let mut resolved = Vec::new();
+    let err = send_results(
+        (1..5).map(|x| {
+            resolved.push(x);
+            check(x)
+        }),
+        &tx,
+    )
+    .unwrap_err();
+    assert_eq!(err.0, Err("1 is odd".to_string()));
+    assert_eq!(resolved, [1]);
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome};
+use std::time::Duration;
+
+#[iex]
+fn delayed(delay: Duration, value: Result<u32, &'static str>) -> Result<u32, &'static str> {
+    std::thread::sleep(delay);
+    value
+}
+
+#[test]
+fn fast_success() {
+    let outcome = delayed(Duration::ZERO, Ok(1));
+    assert_eq!(
+        outcome.resolve_or_default_within(Duration::from_secs(10), 0),
+        1
+    );
+}
+
+#[test]
+fn fast_error() {
+    let outcome = delayed(Duration::ZERO, Err("error"));
+    assert_eq!(
+        outcome.resolve_or_default_within(Duration::from_secs(10), 0),
+        0
+    );
+}
+
+#[cfg(not(feature = "plain"))]
+#[test]
+fn slow_default() {
+    let start = std::time::Instant::now();
+    let outcome = delayed(Duration::from_secs(10), Ok(1));
+    assert_eq!(
+        outcome.resolve_or_default_within(Duration::from_millis(10), 0),
+        0
+    );
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
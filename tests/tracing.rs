@@ -0,0 +1,86 @@
+#![cfg(all(feature = "std", feature = "tracing", not(feature = "plain")))]
+
+use iex::{iex, Outcome};
+use std::sync::{Arc, Mutex};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+// Records the name of the innermost entered span for each event
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<RecorderState>>);
+
+#[derive(Default)]
+struct RecorderState {
+    names: Vec<&'static str>,
+    stack: Vec<u64>,
+    events: Vec<(&'static str, Option<&'static str>)>,
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut state = self.0.lock().unwrap();
+        state.names.push(span.metadata().name());
+        Id::from_u64(state.names.len() as u64)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut state = self.0.lock().unwrap();
+        let span = state.stack.last().map(|&id| state.names[id as usize - 1]);
+        let message = event.metadata().name();
+        state.events.push((message, span));
+    }
+
+    fn enter(&self, span: &Id) {
+        self.0.lock().unwrap().stack.push(span.into_u64());
+    }
+
+    fn exit(&self, span: &Id) {
+        assert_eq!(self.0.lock().unwrap().stack.pop(), Some(span.into_u64()));
+    }
+}
+
+#[iex]
+fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    tracing::info!(name: "parsing", "parsing {s}");
+    s.parse()
+}
+
+#[iex]
+fn parse_port(s: &str) -> Result<u32, std::num::ParseIntError> {
+    parse(s).in_span(tracing::info_span!("port"))
+}
+
+fn record(f: impl FnOnce()) -> RecorderState {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), f);
+    let mut state = recorder.0.lock().unwrap();
+    std::mem::take(&mut *state)
+}
+
+#[test]
+fn in_span_ok() {
+    let state = record(|| {
+        assert_eq!(parse_port("80").into_result(), Ok(80));
+        tracing::info!(name: "after", "after");
+    });
+    assert_eq!(state.events, [("parsing", Some("port")), ("after", None)]);
+    assert!(state.stack.is_empty());
+}
+
+#[test]
+fn in_span_err() {
+    let state = record(|| {
+        assert!(parse_port("x").into_result().is_err());
+        tracing::info!(name: "after", "after");
+    });
+    assert_eq!(state.events, [("parsing", Some("port")), ("after", None)]);
+    assert!(state.stack.is_empty());
+}
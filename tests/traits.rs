@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use iex::{iex, Outcome};
 
 trait FallibleSum {
@@ -59,3 +61,171 @@ fn refined() {
     );
     assert_eq!("test".say_hello().into_result().unwrap(), "test");
 }
+
+trait Lookup {
+    type Error<'a>
+    where
+        Self: 'a;
+
+    #[iex]
+    fn lookup(&self, key: u32) -> Result<u32, Self::Error<'_>>;
+}
+
+struct Table(Vec<u32>);
+
+impl Lookup for Table {
+    type Error<'a> = &'a [u32];
+
+    #[iex]
+    fn lookup(&self, key: u32) -> Result<u32, Self::Error<'_>> {
+        self.0.get(key as usize).copied().ok_or(self.0.as_slice())
+    }
+}
+
+#[iex]
+fn lookup_twice<'a, L: Lookup>(table: &'a L, a: u32, b: u32) -> Result<u32, L::Error<'a>> {
+    Ok(table.lookup(a)? + table.lookup(b)?)
+}
+
+#[test]
+fn generic_associated_type_error() {
+    let table = Table(vec![1, 2]);
+    assert_eq!(table.lookup(1).into_result(), Ok(2));
+    assert_eq!(table.lookup(2).into_result(), Err(&[1, 2][..]));
+    assert_eq!(lookup_twice(&table, 0, 1).into_result(), Ok(3));
+    assert_eq!(lookup_twice(&table, 0, 5).into_result(), Err(&[1, 2][..]));
+}
+
+trait Handler {
+    #[iex(boxed)]
+    fn handle(&self, request: &str) -> Result<u32, String>;
+
+    #[iex(boxed)]
+    fn handle_all(&self, requests: &[&str]) -> Result<u32, String> {
+        let mut total = 0;
+        for request in requests {
+            total += self.handle(request)?;
+        }
+        Ok(total)
+    }
+}
+
+struct Parse;
+
+impl Handler for Parse {
+    #[iex(boxed)]
+    fn handle(&self, request: &str) -> Result<u32, String> {
+        request
+            .parse()
+            .map_err(|_| format!("not a number: {request}"))
+    }
+}
+
+struct Limit(u32);
+
+impl Handler for Limit {
+    #[iex(boxed)]
+    fn handle(&self, request: &str) -> Result<u32, String> {
+        let value = Parse.handle(request)?;
+        if value > self.0 {
+            return Err(format!("{value} exceeds {}", self.0));
+        }
+        Ok(value)
+    }
+}
+
+impl Limit {
+    #[iex(boxed)]
+    fn handle_each(
+        &self,
+        requests: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<Vec<u32>, String> {
+        let mut values = Vec::new();
+        for request in requests {
+            values.push(self.handle(request.as_ref())?);
+        }
+        Ok(values)
+    }
+}
+
+#[iex]
+fn dispatch(handlers: &[Box<dyn Handler>], request: &str) -> Result<Vec<u32>, String> {
+    let mut results = Vec::new();
+    for handler in handlers {
+        results.push(handler.handle(request)?);
+    }
+    Ok(results)
+}
+
+#[test]
+fn object_safe() {
+    let handlers: Vec<Box<dyn Handler>> = vec![Box::new(Parse), Box::new(Limit(10))];
+    assert_eq!(dispatch(&handlers, "7").into_result(), Ok(vec![7, 7]));
+    assert_eq!(
+        dispatch(&handlers, "12").into_result(),
+        Err("12 exceeds 10".to_string()),
+    );
+    assert_eq!(
+        dispatch(&handlers, "x").into_result(),
+        Err("not a number: x".to_string()),
+    );
+    assert_eq!(handlers[1].handle_all(&["1", "2"]).into_result(), Ok(3));
+    assert_eq!(
+        handlers[1].handle_all(&["1", "20"]).into_result(),
+        Err("20 exceeds 10".to_string()),
+    );
+}
+
+#[test]
+fn boxed_impl_trait_argument() {
+    let requests = vec!["1".to_string(), "2".to_string()];
+    assert_eq!(
+        Limit(10).handle_each(&requests).into_result(),
+        Ok(vec![1, 2])
+    );
+    assert_eq!(
+        Limit(10).handle_each(["1", "20"]).into_result(),
+        Err("20 exceeds 10".to_string()),
+    );
+}
+
+trait Source {
+    type Err;
+
+    #[iex]
+    fn read(&self) -> Result<u32, Self::Err>;
+
+    #[iex]
+    fn read_sum(&self) -> Result<u32, Self::Err> {
+        Ok(self.read()? + self.read()?)
+    }
+}
+
+struct Fixed(Option<u32>);
+
+impl Source for Fixed {
+    type Err = &'static str;
+
+    #[iex]
+    fn read(&self) -> Result<u32, Self::Err> {
+        self.0.ok_or("empty")
+    }
+}
+
+impl Fixed {
+    // `Self::Err` is ambiguous outside of the trait impl, as in any function
+    #[iex]
+    fn read_doubled(&self) -> Result<u32, <Self as Source>::Err> {
+        Ok(self.read()? * 2)
+    }
+}
+
+#[test]
+fn associated_type_error() {
+    assert_eq!(Fixed(Some(2)).read().into_result(), Ok(2));
+    assert_eq!(Fixed(None).read().into_result(), Err("empty"));
+    assert_eq!(Fixed(Some(2)).read_sum().into_result(), Ok(4));
+    assert_eq!(Fixed(None).read_sum().into_result(), Err("empty"));
+    assert_eq!(Fixed(Some(2)).read_doubled().into_result(), Ok(4));
+    assert_eq!(Fixed(None).read_doubled().into_result(), Err("empty"));
+}
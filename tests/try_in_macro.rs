@@ -2,6 +2,9 @@
 //! That that we can use `?` in a macro
 //!
 
+// The `?` is resolved natively with the `plain` feature, which this test is not about
+#![cfg(all(feature = "std", not(feature = "plain")))]
+
 use iex::Outcome;
 
 #[iex::iex]
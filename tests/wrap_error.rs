@@ -0,0 +1,75 @@
+#![cfg(feature = "std")]
+
+use iex::{iex, Outcome, WrapError};
+use std::error::Error;
+
+#[iex]
+fn parse(s: &str) -> Result<u16, std::num::ParseIntError> {
+    s.parse()
+}
+
+#[iex]
+fn parse_port(s: &str) -> Result<u16, WrapError<std::num::ParseIntError>> {
+    parse(s).map_err_wrap("Failed to parse port")
+}
+
+#[iex]
+fn load_config(
+    path: &str,
+    port: &str,
+) -> Result<u16, WrapError<WrapError<std::num::ParseIntError>>> {
+    parse_port(port).map_err_wrap(format!("Failed to load {path}"))
+}
+
+fn chain(err: &dyn Error) -> Vec<String> {
+    let mut messages = vec![err.to_string()];
+    let mut source = err.source();
+    while let Some(err) = source {
+        messages.push(err.to_string());
+        source = err.source();
+    }
+    messages
+}
+
+#[test]
+fn wrap_ok() {
+    assert_eq!(load_config("config.toml", "80").into_result().unwrap(), 80);
+}
+
+#[test]
+fn source_chain() {
+    let err = load_config("config.toml", "x").into_result().unwrap_err();
+    assert_eq!(
+        chain(&err),
+        [
+            "Failed to load config.toml",
+            "Failed to parse port",
+            "invalid digit found in string",
+        ]
+    );
+    assert_eq!(err.error.error, "x".parse::<u16>().unwrap_err());
+}
+
+#[test]
+fn context_is_lazy() {
+    struct Context<'a>(&'a std::cell::Cell<u32>);
+
+    impl From<Context<'_>> for String {
+        fn from(context: Context<'_>) -> String {
+            context.0.set(context.0.get() + 1);
+            "context".to_string()
+        }
+    }
+
+    let conversions = std::cell::Cell::new(0);
+    assert!(parse("1")
+        .map_err_wrap(Context(&conversions))
+        .into_result()
+        .is_ok());
+    assert_eq!(conversions.get(), 0);
+    assert!(parse("x")
+        .map_err_wrap(Context(&conversions))
+        .into_result()
+        .is_err());
+    assert_eq!(conversions.get(), 1);
+}